@@ -0,0 +1,54 @@
+extern crate alloc;
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/dataset/rows.rs"]
+mod rows;
+
+const DATASET: &str = "data/prefixes.csv";
+const VARIANTS: [&str; 5] = ["Globe", "Smart", "Sun", "Tnt", "Dito"];
+
+fn date_literal((year, month, day): rows::Ymd) -> String {
+    format!("Date {{ year: {}, month: {}, day: {} }}", year, month, day)
+}
+
+fn optional_date_literal(date: Option<rows::Ymd>) -> String {
+    match date {
+        Some(date) => format!("Some({})", date_literal(date)),
+        None => "None".to_string(),
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DATASET);
+    println!("cargo:rerun-if-changed=src/dataset/rows.rs");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let data =
+        fs::read_to_string(DATASET).unwrap_or_else(|e| panic!("failed to read {}: {}", DATASET, e));
+    let rows =
+        rows::parse_rows(&data).unwrap_or_else(|(line, e)| panic!("{}:{}: {}", DATASET, line, e));
+
+    let mut table = String::from("pub static PREFIX_TABLE: &[PrefixRecord] = &[\n");
+    for row in &rows {
+        writeln!(
+            table,
+            "    PrefixRecord {{ prefix: {:?}, operator: Operator::{}, effective_from: {}, effective_to: {}, source: {:?}, date: {} }},",
+            row.prefix,
+            VARIANTS[row.operator],
+            optional_date_literal(row.effective_from),
+            optional_date_literal(row.effective_to),
+            row.source,
+            date_literal(row.date)
+        )
+        .unwrap();
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("prefix_table.rs");
+    fs::write(out, table).unwrap();
+}
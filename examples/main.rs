@@ -24,9 +24,9 @@ fn main() {
     // Get the network based on the phone number and validate the number
     match MobileNetwork::get(number) {
         Ok(network) => {
-            println!("Network identified: {}", network.to_string());
+            println!("Network identified: {}", network);
             match network.validate(number) {
-                Ok(valid) => println!("Validation result: {}", valid),
+                Ok(valid) => println!("Validated: {} ({})", valid, valid.operator()),
                 Err(e) => println!("Validation error: {:?}", e),
             }
         },
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ph_mobile_network::{dataset::Dataset, registry::Registry};
+
+fuzz_target!(|text: &str| {
+    if let Ok(dataset) = Dataset::parse(text) {
+        let registry = Registry::new(dataset);
+        let _ = registry.classify("09171234567");
+    }
+});
@@ -0,0 +1,37 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ph_mobile_network::{
+    classify::classify,
+    extract::extract,
+    date::Date,
+    format::{format, normalize, NumberFormat},
+    mobile_network::MobileNetwork,
+    registry::Registry,
+    validate::Validate,
+};
+
+fuzz_target!(|input: &str| {
+    let _ = MobileNetwork::get(input);
+    let _ = MobileNetwork::get_as_of(input, Date::new(2024, 1, 1).unwrap());
+    let _ = Registry::bundled().validate(input);
+    for found in extract(input) {
+        assert_eq!(&input[found.span], found.text);
+    }
+
+    let classified = classify(input);
+    if let Ok(national) = normalize(input) {
+        assert_eq!(normalize(&national).unwrap(), national);
+        for style in [
+            NumberFormat::National,
+            NumberFormat::International,
+            NumberFormat::E164,
+        ] {
+            let formatted = format(&national, style).unwrap();
+            assert_eq!(normalize(&formatted).unwrap(), national);
+        }
+        if let Ok(operator) = classified {
+            assert_eq!(classify(&national).unwrap(), operator);
+        }
+    }
+});
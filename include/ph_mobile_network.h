@@ -0,0 +1,115 @@
+#ifndef PH_MOBILE_NETWORK_H
+#define PH_MOBILE_NETWORK_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Buffer size large enough for any string written by this library, including the NUL.
+ */
+#define PH_BUFFER_LEN 32
+
+typedef enum PhStatus {
+  PH_STATUS_OK = 0,
+  PH_STATUS_INVALID_LENGTH = 1,
+  PH_STATUS_NON_NUMERIC = 2,
+  PH_STATUS_UNRECOGNIZED_PREFIX = 3,
+  PH_STATUS_REGEX_ERROR = 4,
+  PH_STATUS_MUTEX_ERROR = 5,
+  PH_STATUS_INVALID_DATE = 6,
+  PH_STATUS_INVALID_DATASET = 7,
+  PH_STATUS_RESOLVER_ERROR = 8,
+  PH_STATUS_OPERATOR_MISMATCH = 9,
+  PH_STATUS_UNKNOWN_OPERATOR = 10,
+  PH_STATUS_UNICODE_DIGIT = 11,
+  PH_STATUS_NULL_POINTER = 100,
+  PH_STATUS_INVALID_UTF8 = 101,
+  PH_STATUS_BUFFER_TOO_SMALL = 102,
+  PH_STATUS_INVALID_ARGUMENT = 103,
+} PhStatus;
+
+/**
+ * Stable operator codes. Values never change once released.
+ */
+typedef enum PhOperator {
+  PH_OPERATOR_UNKNOWN = 0,
+  PH_OPERATOR_GLOBE = 1,
+  PH_OPERATOR_SMART = 2,
+  PH_OPERATOR_SUN = 3,
+  PH_OPERATOR_TNT = 4,
+  PH_OPERATOR_DITO = 5,
+} PhOperator;
+
+typedef enum PhFormat {
+  PH_FORMAT_NATIONAL = 0,
+  PH_FORMAT_INTERNATIONAL = 1,
+  PH_FORMAT_E164 = 2,
+} PhFormat;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Looks up the operator of `number` and stores it in `out_operator`. Uses the
+ * lock-free `classify`, so it can be called from any number of threads at once.
+ *
+ * # Safety
+ *
+ * `number` must be a valid NUL terminated string and `out_operator` must point to
+ * writable memory for one `PhOperator`.
+ */
+enum PhStatus ph_operator_of(const char *number, enum PhOperator *out_operator);
+
+/**
+ * Returns `PH_STATUS_OK` when `number` is a valid mobile number of its operator.
+ *
+ * # Safety
+ *
+ * `number` must be a valid NUL terminated string.
+ */
+enum PhStatus ph_validate(const char *number);
+
+/**
+ * Writes the 11 digit national form of `number` into `buffer`.
+ *
+ * # Safety
+ *
+ * `number` must be a valid NUL terminated string and `buffer` must point to
+ * at least `buffer_len` writable bytes.
+ */
+enum PhStatus ph_parse(const char *number, char *buffer, size_t buffer_len);
+
+/**
+ * Writes `number` in the requested `format`, a `PhFormat` value, into `buffer`.
+ * Returns `PH_STATUS_INVALID_ARGUMENT` for any other value.
+ *
+ * # Safety
+ *
+ * `number` must be a valid NUL terminated string and `buffer` must point to
+ * at least `buffer_len` writable bytes.
+ */
+enum PhStatus ph_format(const char *number, uint32_t format, char *buffer, size_t buffer_len);
+
+/**
+ * Returns the lowercase name of `operator`, a `PhOperator` value, as a static NUL
+ * terminated string; `"unknown"` for any other value.
+ */
+const char *ph_operator_name(uint32_t operator_);
+
+/**
+ * Returns a static NUL terminated description of `status`, a `PhStatus` value;
+ * `"unknown"` for any other value.
+ */
+const char *ph_status_message(uint32_t status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PH_MOBILE_NETWORK_H */
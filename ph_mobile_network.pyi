@@ -0,0 +1,22 @@
+from typing import List, Literal, Optional, Sequence
+
+class MobileNetworkError(ValueError): ...
+class InvalidLength(MobileNetworkError): ...
+class NonNumeric(MobileNetworkError): ...
+class UnrecognizedPrefix(MobileNetworkError): ...
+class RegexError(MobileNetworkError): ...
+class MutexError(MobileNetworkError): ...
+class InvalidDate(MobileNetworkError): ...
+class InvalidDataset(MobileNetworkError): ...
+class ResolverError(MobileNetworkError): ...
+class OperatorMismatch(MobileNetworkError): ...
+class UnknownOperator(MobileNetworkError): ...
+class UnicodeDigit(MobileNetworkError): ...
+
+def parse(number: str) -> str: ...
+def validate(number: str) -> bool: ...
+def format(
+    number: str, style: Literal["national", "international", "e164"] = "national"
+) -> str: ...
+def operator_of(number: str) -> str: ...
+def classify_many(numbers: Sequence[str]) -> List[Optional[str]]: ...
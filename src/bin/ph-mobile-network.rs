@@ -0,0 +1,99 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use ph_mobile_network::{dataset::Dataset, registry::Registry, stats::Stats};
+
+const USAGE: &str = "Usage: ph-mobile-network <command>
+
+Commands:
+  diff [--json] <old> <new>  Compare two prefix datasets. Each side is a path to a
+                             file in the data/prefixes.csv format, or `bundled` for
+                             the dataset compiled into the library.
+  report [--json|--csv] [--dataset <path>] [<numbers>]
+                             Count numbers by operator, brand, prefix and error,
+                             with percentages, as a Markdown table unless --json
+                             or --csv is given. Numbers are read one per line from
+                             the file, or from standard input if it is omitted or
+                             `-`, and looked up in the bundled dataset unless
+                             --dataset is given.";
+
+fn load(source: &str) -> Result<Dataset, String> {
+    if source == "bundled" {
+        Ok(Dataset::bundled())
+    } else {
+        Dataset::load(source).map_err(|e| e.to_string())
+    }
+}
+
+fn diff(args: &[String]) -> Result<(), String> {
+    let json = args.iter().any(|arg| arg == "--json");
+    let paths: Vec<&String> = args.iter().filter(|arg| *arg != "--json").collect();
+    let [old, new] = paths[..] else {
+        return Err(USAGE.to_string());
+    };
+
+    let diff = load(old)?.diff(&load(new)?);
+    if json {
+        println!("{}", diff.to_json());
+    } else {
+        print!("{}", diff);
+    }
+    Ok(())
+}
+
+fn report(args: &[String]) -> Result<(), String> {
+    let mut output = Stats::to_markdown as fn(&Stats) -> String;
+    let mut stats = Stats::new();
+    let mut input = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => output = Stats::to_json,
+            "--csv" => output = Stats::to_csv,
+            "--markdown" => output = Stats::to_markdown,
+            "--dataset" => {
+                let path = args.next().ok_or_else(|| USAGE.to_string())?;
+                stats = stats.with_registry(Registry::new(load(path)?));
+            }
+            _ if input.is_none() => input = Some(arg.as_str()),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let numbers = match input {
+        None | Some("-") => {
+            let mut numbers = String::new();
+            io::stdin()
+                .read_to_string(&mut numbers)
+                .map_err(|e| e.to_string())?;
+            numbers
+        }
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?,
+    };
+    stats.extend(numbers.lines().filter(|line| !line.trim().is_empty()));
+    println!("{}", output(&stats).trim_end());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("diff") => diff(&args[1..]),
+        Some("report") => report(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
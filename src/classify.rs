@@ -0,0 +1,219 @@
+use alloc::string::ToString;
+
+use crate::{
+    date::Date,
+    errors::MobileNetworkError,
+    globals::prefix_table::{PrefixRecord, PREFIX_TABLE},
+    numerals,
+    operator::Operator,
+    operator_set::OperatorSet,
+};
+
+/// Classifies `number` against the bundled prefix table without regex or locks,
+/// so it is available without `std`. The longest matching prefix wins, which lets
+/// five digit assignments such as `09253` override the four digit block they sit in.
+///
+/// With `std` this uses the assignments effective today. Without `std` there is no
+/// clock, so it uses the current assignments (those without an `effective_to` date).
+pub fn classify(number: &str) -> Result<Operator, MobileNetworkError> {
+    #[cfg(feature = "std")]
+    return classify_as_of(number, Date::today());
+    #[cfg(not(feature = "std"))]
+    return classify_matching(number, PrefixRecord::is_current);
+}
+
+/// Whether `number` belongs to one of `operators`, e.g. to open a promo to Globe
+/// and TM only. Invalid and unrecognized numbers are errors, not `false`.
+pub fn matches(number: &str, operators: &OperatorSet) -> Result<bool, MobileNetworkError> {
+    classify(number).map(|operator| operators.contains(operator))
+}
+
+/// Classifies `number` using the assignments that were effective on `date`.
+pub fn classify_as_of(number: &str, date: Date) -> Result<Operator, MobileNetworkError> {
+    classify_matching(number, |record| record.is_effective_on(date))
+}
+
+fn classify_matching(
+    number: &str,
+    effective: impl Fn(&PrefixRecord) -> bool,
+) -> Result<Operator, MobileNetworkError> {
+    matching_prefix(number, effective).map(|(_, operator)| operator)
+}
+
+/// Like `classify`, but also returns the table prefix that matched, e.g. `09253`.
+pub(crate) fn classify_prefix(
+    number: &str,
+) -> Result<(&'static str, Operator), MobileNetworkError> {
+    #[cfg(feature = "std")]
+    {
+        let today = Date::today();
+        matching_prefix(number, |record| record.is_effective_on(today))
+    }
+    #[cfg(not(feature = "std"))]
+    matching_prefix(number, PrefixRecord::is_current)
+}
+
+fn matching_prefix(
+    number: &str,
+    effective: impl Fn(&PrefixRecord) -> bool,
+) -> Result<(&'static str, Operator), MobileNetworkError> {
+    longest_prefix(
+        number,
+        PREFIX_TABLE
+            .iter()
+            .filter(|record| effective(record))
+            .map(|record| (record.prefix, record.operator)),
+    )
+}
+
+/// Checks that `number` is 11 ASCII digits and returns the operator of the longest
+/// candidate prefix it starts with. Earlier candidates win ties.
+pub(crate) fn longest_match<'a>(
+    number: &str,
+    candidates: impl IntoIterator<Item = (&'a str, Operator)>,
+) -> Result<Operator, MobileNetworkError> {
+    longest_prefix(number, candidates).map(|(_, operator)| operator)
+}
+
+/// Like `longest_match`, returning the matching prefix too.
+pub(crate) fn longest_prefix<'a>(
+    number: &str,
+    candidates: impl IntoIterator<Item = (&'a str, Operator)>,
+) -> Result<(&'a str, Operator), MobileNetworkError> {
+    let bytes = number.as_bytes();
+    if !bytes.iter().all(u8::is_ascii_digit) {
+        numerals::reject(number)?;
+        return Err(MobileNetworkError::NonNumeric);
+    }
+    if bytes.len() != 11 {
+        return Err(MobileNetworkError::InvalidLength);
+    }
+
+    let mut found: Option<(&str, Operator)> = None;
+    for (prefix, operator) in candidates {
+        let longer = found.is_none_or(|(best, _)| prefix.len() > best.len());
+        if longer && number.starts_with(prefix) {
+            found = Some((prefix, operator));
+        }
+    }
+
+    found.ok_or_else(|| MobileNetworkError::UnrecognizedPrefix(number[..4].to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_all_operators() {
+        assert_eq!(classify("09171234567").unwrap(), Operator::Globe);
+        assert_eq!(classify("09191234567").unwrap(), Operator::Smart);
+        assert_eq!(classify("09221234567").unwrap(), Operator::Sun);
+        assert_eq!(classify("09301234567").unwrap(), Operator::Tnt);
+        assert_eq!(classify("08971234567").unwrap(), Operator::Dito);
+    }
+
+    #[test]
+    fn matches_operator_sets() {
+        let promo: OperatorSet = "globe,tm".parse().unwrap();
+        assert!(matches("09171234567", &promo).unwrap());
+        assert!(!matches("09191234567", &promo).unwrap());
+        assert!(matches(
+            "09191234567",
+            &OperatorSet::from(Operator::Dito).complement()
+        )
+        .unwrap());
+        assert!(matches("0917", &promo).is_err());
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        assert_eq!(classify("09251234567").unwrap(), Operator::Sun);
+        assert_eq!(classify("09253123456").unwrap(), Operator::Globe);
+    }
+
+    #[test]
+    fn formerly_shared_prefix_stays_with_smart() {
+        assert_eq!(classify("09461234567").unwrap(), Operator::Smart);
+    }
+
+    #[test]
+    fn classify_as_of_uses_effective_assignments_only() {
+        let date = Date::new(1990, 1, 1).unwrap();
+        assert_eq!(
+            classify_as_of("08171234567", date).unwrap(),
+            Operator::Globe
+        );
+        assert!(matches!(
+            classify_as_of("08971234567", Date::new(2021, 3, 7).unwrap()),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+        assert_eq!(
+            classify_as_of("08971234567", Date::new(2021, 3, 8).unwrap()).unwrap(),
+            Operator::Dito
+        );
+        assert!(matches!(
+            classify_matching("08171234567", |record| record.prefix != "0817"),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn is_err_for_non_numeric() {
+        assert!(matches!(
+            classify("0917abc4567"),
+            Err(MobileNetworkError::NonNumeric)
+        ));
+    }
+
+    #[test]
+    fn is_err_for_unicode_digits() {
+        assert!(matches!(
+            classify("091７1234567"),
+            Err(MobileNetworkError::UnicodeDigit(text)) if text.contains("byte 3")
+        ));
+        assert!(matches!(
+            classify("０９１７1234567"),
+            Err(MobileNetworkError::UnicodeDigit(_))
+        ));
+    }
+
+    #[test]
+    fn is_err_on_invalid_length() {
+        assert!(matches!(
+            classify("0917123456"),
+            Err(MobileNetworkError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn is_err_on_unrecognized_prefix() {
+        assert!(matches!(
+            classify("09871234567"),
+            Err(MobileNetworkError::UnrecognizedPrefix(prefix)) if prefix == "0987"
+        ));
+    }
+
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    mod properties {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn classify_never_panics(input in any::<String>()) {
+                let _ = classify(&input);
+            }
+
+            #[test]
+            fn classify_agrees_with_matches(number in "0[89][0-9]{9}") {
+                let all = OperatorSet::all();
+                prop_assert_eq!(classify(&number).is_ok(), matches(&number, &all).is_ok());
+                if let Ok(operator) = classify(&number) {
+                    prop_assert!(matches(&number, &operator.into()).unwrap());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Write as _};
+
+use super::Assignment;
+use crate::operator::Operator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixChange {
+    pub prefix: String,
+    pub operator: Operator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reassignment {
+    pub prefix: String,
+    pub from: Operator,
+    pub to: Operator,
+}
+
+/// Differences between the assignments of two datasets, sorted by prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatasetDiff {
+    pub added: Vec<PrefixChange>,
+    pub removed: Vec<PrefixChange>,
+    pub reassigned: Vec<Reassignment>,
+}
+
+impl DatasetDiff {
+    pub(crate) fn between<'a>(
+        old: impl Iterator<Item = &'a Assignment>,
+        new: impl Iterator<Item = &'a Assignment>,
+    ) -> Self {
+        let old: BTreeMap<&str, Operator> = old.map(|a| (a.prefix.as_str(), a.operator)).collect();
+        let new: BTreeMap<&str, Operator> = new.map(|a| (a.prefix.as_str(), a.operator)).collect();
+        let mut diff = DatasetDiff::default();
+
+        for (&prefix, &operator) in &new {
+            match old.get(prefix) {
+                None => diff.added.push(PrefixChange {
+                    prefix: prefix.into(),
+                    operator,
+                }),
+                Some(&from) if from != operator => diff.reassigned.push(Reassignment {
+                    prefix: prefix.into(),
+                    from,
+                    to: operator,
+                }),
+                Some(_) => {}
+            }
+        }
+        for (&prefix, &operator) in &old {
+            if !new.contains_key(prefix) {
+                diff.removed.push(PrefixChange {
+                    prefix: prefix.into(),
+                    operator,
+                });
+            }
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.reassigned.is_empty()
+    }
+
+    fn added_to(&self, operator: Operator) -> impl Iterator<Item = &PrefixChange> {
+        self.added.iter().filter(move |c| c.operator == operator)
+    }
+
+    fn removed_from(&self, operator: Operator) -> impl Iterator<Item = &PrefixChange> {
+        self.removed.iter().filter(move |c| c.operator == operator)
+    }
+
+    fn moved_in(&self, operator: Operator) -> impl Iterator<Item = &Reassignment> {
+        self.reassigned.iter().filter(move |r| r.to == operator)
+    }
+
+    fn moved_out(&self, operator: Operator) -> impl Iterator<Item = &Reassignment> {
+        self.reassigned.iter().filter(move |r| r.from == operator)
+    }
+
+    fn touches(&self, operator: Operator) -> bool {
+        self.added_to(operator).next().is_some()
+            || self.removed_from(operator).next().is_some()
+            || self.moved_in(operator).next().is_some()
+            || self.moved_out(operator).next().is_some()
+    }
+
+    /// Renders the changes grouped per operator as a JSON object. Operators without
+    /// changes are left out.
+    pub fn to_json(&self) -> String {
+        let prefixes = |changes: &mut dyn Iterator<Item = &PrefixChange>| {
+            let quoted: Vec<String> = changes
+                .map(|c| alloc::format!("\"{}\"", c.prefix))
+                .collect();
+            quoted.join(",")
+        };
+
+        let mut json = String::from("{");
+        for (i, operator) in Operator::iter().filter(|&o| self.touches(o)).enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let moved_in: Vec<String> = self
+                .moved_in(operator)
+                .map(|r| alloc::format!("{{\"prefix\":\"{}\",\"from\":\"{}\"}}", r.prefix, r.from))
+                .collect();
+            let moved_out: Vec<String> = self
+                .moved_out(operator)
+                .map(|r| alloc::format!("{{\"prefix\":\"{}\",\"to\":\"{}\"}}", r.prefix, r.to))
+                .collect();
+            let _ = write!(
+                json,
+                "\"{}\":{{\"added\":[{}],\"removed\":[{}],\"reassigned_in\":[{}],\"reassigned_out\":[{}]}}",
+                operator,
+                prefixes(&mut self.added_to(operator)),
+                prefixes(&mut self.removed_from(operator)),
+                moved_in.join(","),
+                moved_out.join(",")
+            );
+        }
+        json.push('}');
+        json
+    }
+}
+
+impl fmt::Display for DatasetDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no changes");
+        }
+
+        for operator in Operator::iter().filter(|&o| self.touches(o)) {
+            writeln!(f, "{}", operator)?;
+            for change in self.added_to(operator) {
+                writeln!(f, "  + {}", change.prefix)?;
+            }
+            for change in self.removed_from(operator) {
+                writeln!(f, "  - {}", change.prefix)?;
+            }
+            for moved in self.moved_in(operator) {
+                writeln!(f, "  ~ {} (from {})", moved.prefix, moved.from)?;
+            }
+            for moved in self.moved_out(operator) {
+                writeln!(f, "  ~ {} (to {})", moved.prefix, moved.to)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::dataset::Dataset;
+
+    fn dataset(rows: &str) -> Dataset {
+        Dataset::parse(&alloc::format!(
+            "prefix,operator,effective_from,effective_to,source,date\n{}",
+            rows
+        ))
+        .unwrap()
+    }
+
+    fn sample() -> DatasetDiff {
+        let old = dataset(
+            "0817,globe,,,test,2024-01-01\n0946,tnt,,,test,2024-01-01\n0895,dito,,,test,2024-01-01\n",
+        );
+        let new = dataset(
+            "0817,globe,,,test,2024-01-01\n0946,smart,,,test,2024-01-01\n0904,globe,,,test,2024-01-01\n",
+        );
+        old.diff(&new)
+    }
+
+    #[test]
+    fn reports_added_removed_and_reassigned() {
+        let diff = sample();
+        assert_eq!(
+            diff.added,
+            [PrefixChange {
+                prefix: "0904".into(),
+                operator: Operator::Globe
+            }]
+        );
+        assert_eq!(
+            diff.removed,
+            [PrefixChange {
+                prefix: "0895".into(),
+                operator: Operator::Dito
+            }]
+        );
+        assert_eq!(
+            diff.reassigned,
+            [Reassignment {
+                prefix: "0946".into(),
+                from: Operator::Tnt,
+                to: Operator::Smart
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_assignments_that_ended() {
+        let old = dataset("0817,globe,,,test,2024-01-01\n");
+        let new = dataset("0817,globe,,2024-01-01,test,2024-01-01\n");
+        assert_eq!(old.diff(&new).removed.len(), 1);
+        assert!(Dataset::bundled().diff(&Dataset::bundled()).is_empty());
+    }
+
+    #[test]
+    fn displays_changes_per_operator() {
+        assert_eq!(
+            sample().to_string(),
+            "globe\n  + 0904\nsmart\n  ~ 0946 (from tnt)\ntnt\n  ~ 0946 (to smart)\ndito\n  - 0895\n"
+        );
+        assert_eq!(DatasetDiff::default().to_string(), "no changes\n");
+    }
+
+    #[test]
+    fn renders_json() {
+        assert_eq!(
+            sample().to_json(),
+            concat!(
+                "{\"globe\":{\"added\":[\"0904\"],\"removed\":[],\"reassigned_in\":[],\"reassigned_out\":[]},",
+                "\"smart\":{\"added\":[],\"removed\":[],\"reassigned_in\":[{\"prefix\":\"0946\",\"from\":\"tnt\"}],\"reassigned_out\":[]},",
+                "\"tnt\":{\"added\":[],\"removed\":[],\"reassigned_in\":[],\"reassigned_out\":[{\"prefix\":\"0946\",\"to\":\"smart\"}]},",
+                "\"dito\":{\"added\":[],\"removed\":[\"0895\"],\"reassigned_in\":[],\"reassigned_out\":[]}}"
+            )
+        );
+        assert_eq!(DatasetDiff::default().to_json(), "{}");
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    date::Date,
+    errors::MobileNetworkError,
+    globals::prefix_table::{PrefixRecord, PREFIX_TABLE},
+    operator::Operator,
+};
+
+pub mod diff;
+pub(crate) mod rows;
+
+pub use diff::DatasetDiff;
+
+/// An owned prefix assignment, as loaded from a dataset file at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub prefix: String,
+    pub operator: Operator,
+    pub effective_from: Option<Date>,
+    pub effective_to: Option<Date>,
+    pub source: String,
+    pub date: Date,
+}
+
+impl Assignment {
+    pub fn is_effective_on(&self, date: Date) -> bool {
+        self.effective_from.is_none_or(|from| from <= date)
+            && self.effective_to.is_none_or(|to| date < to)
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.effective_to.is_none()
+    }
+}
+
+impl From<&PrefixRecord> for Assignment {
+    fn from(record: &PrefixRecord) -> Self {
+        Self {
+            prefix: record.prefix.to_string(),
+            operator: record.operator,
+            effective_from: record.effective_from,
+            effective_to: record.effective_to,
+            source: record.source.to_string(),
+            date: record.date,
+        }
+    }
+}
+
+/// A set of prefix assignments in the `data/prefixes.csv` format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dataset {
+    assignments: Vec<Assignment>,
+}
+
+impl Dataset {
+    /// The dataset compiled into the library.
+    pub fn bundled() -> Self {
+        Self {
+            assignments: PREFIX_TABLE.iter().map(Assignment::from).collect(),
+        }
+    }
+
+    /// Parses a dataset, applying the same checks as the build script.
+    pub fn parse(text: &str) -> Result<Self, MobileNetworkError> {
+        let rows = rows::parse_rows(text).map_err(|(line, error)| {
+            MobileNetworkError::InvalidDataset(format!("line {}: {}", line, error))
+        })?;
+        let date = |(year, month, day)| Date { year, month, day };
+
+        Ok(Self {
+            assignments: rows
+                .into_iter()
+                .map(|row| Assignment {
+                    prefix: row.prefix.to_string(),
+                    operator: Operator::ALL[row.operator],
+                    effective_from: row.effective_from.map(date),
+                    effective_to: row.effective_to.map(date),
+                    source: row.source.to_string(),
+                    date: date(row.date),
+                })
+                .collect(),
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, MobileNetworkError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            MobileNetworkError::InvalidDataset(format!("{}: {}", path.display(), e))
+        })?;
+        Self::parse(&text).map_err(|e| match e {
+            MobileNetworkError::InvalidDataset(reason) => {
+                MobileNetworkError::InvalidDataset(format!("{}: {}", path.display(), reason))
+            }
+            other => other,
+        })
+    }
+
+    pub fn assignments(&self) -> &[Assignment] {
+        &self.assignments
+    }
+
+    /// Assignments without an `effective_to` date.
+    pub fn current(&self) -> impl Iterator<Item = &Assignment> {
+        self.assignments.iter().filter(|a| a.is_current())
+    }
+
+    pub fn as_of(&self, date: Date) -> impl Iterator<Item = &Assignment> {
+        self.assignments
+            .iter()
+            .filter(move |a| a.is_effective_on(date))
+    }
+
+    /// Compares the current assignments of `self` with those of `newer`.
+    pub fn diff(&self, newer: &Dataset) -> DatasetDiff {
+        DatasetDiff::between(self.current(), newer.current())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "prefix,operator,effective_from,effective_to,source,date\n";
+
+    #[test]
+    fn bundled_matches_prefix_table() {
+        let dataset = Dataset::bundled();
+        assert_eq!(dataset.assignments().len(), PREFIX_TABLE.len());
+        assert_eq!(dataset.assignments()[0].prefix, PREFIX_TABLE[0].prefix);
+    }
+
+    #[test]
+    fn parses_dataset() {
+        let text = alloc::format!(
+            "# comment\n{}0917,globe,,,test,2024-01-01\n0946,tnt,,2020-01-01,test,2024-01-01\n0946,smart,2020-01-01,,test,2024-01-01\n",
+            HEADER
+        );
+        let dataset = Dataset::parse(&text).unwrap();
+        assert_eq!(dataset.assignments().len(), 3);
+        assert_eq!(dataset.current().count(), 2);
+
+        let old: Vec<_> = dataset.as_of(Date::new(2019, 1, 1).unwrap()).collect();
+        assert_eq!(old.len(), 2);
+        assert_eq!(old[1].operator, Operator::Tnt);
+    }
+
+    #[test]
+    fn is_err_on_overlapping_assignments() {
+        let text = alloc::format!(
+            "{}0917,globe,,,test,2024-01-01\n0917,smart,2020-01-01,,test,2024-01-01\n",
+            HEADER
+        );
+        assert!(matches!(
+            Dataset::parse(&text),
+            Err(MobileNetworkError::InvalidDataset(reason)) if reason.starts_with("line 3:")
+        ));
+    }
+
+    #[test]
+    fn is_err_on_malformed_rows() {
+        for row in [
+            "917,globe,,,test,2024-01-01",
+            "0917,globe,,,test",
+            "0917,touch,,,test,2024-01-01",
+            "0917,globe,2024-01-01,2023-01-01,test,2024-01-01",
+            "0917,globe,,,,2024-01-01",
+            "0917,globe,,,test,2024-02-30",
+            "0917,globe,,,test,+024-01-01",
+            "0917,globe,2024-+1-01,,test,2024-01-01",
+        ] {
+            let text = alloc::format!("{}{}\n", HEADER, row);
+            assert!(
+                matches!(
+                    Dataset::parse(&text),
+                    Err(MobileNetworkError::InvalidDataset(_))
+                ),
+                "{}",
+                row
+            );
+        }
+        assert!(Dataset::parse("0917,globe,,,test,2024-01-01\n").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+// Parser for the prefix dataset format of `data/prefixes.csv`. It is shared with
+// build.rs through `#[path]`, so it must only depend on `core` and `alloc`.
+
+use alloc::{format, string::String, vec::Vec};
+
+pub(crate) const HEADER: &str = "prefix,operator,effective_from,effective_to,source,date";
+// Same order as `Operator::ALL`.
+pub(crate) const OPERATOR_NAMES: [&str; 5] = ["globe", "smart", "sun", "tnt", "dito"];
+
+pub(crate) type Ymd = (u16, u8, u8);
+
+pub(crate) struct Row<'a> {
+    pub(crate) line: usize,
+    pub(crate) prefix: &'a str,
+    /// Index into `OPERATOR_NAMES`.
+    pub(crate) operator: usize,
+    pub(crate) effective_from: Option<Ymd>,
+    pub(crate) effective_to: Option<Ymd>,
+    pub(crate) source: &'a str,
+    pub(crate) date: Ymd,
+}
+
+impl Row<'_> {
+    fn overlaps(&self, other: &Row<'_>) -> bool {
+        let starts_before_end = |from: Option<Ymd>, to: Option<Ymd>| match (from, to) {
+            (Some(from), Some(to)) => from < to,
+            _ => true,
+        };
+        starts_before_end(self.effective_from, other.effective_to)
+            && starts_before_end(other.effective_from, self.effective_to)
+    }
+}
+
+fn parse_date(value: &str) -> Result<Ymd, String> {
+    let invalid = || format!("malformed date {:?}, expected YYYY-MM-DD", value);
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return Err(invalid());
+    }
+    // `str::parse` alone would also accept a sign, as in `+024`.
+    if !value
+        .bytes()
+        .enumerate()
+        .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let year: u16 = value[..4].parse().map_err(|_| invalid())?;
+    let month: u8 = value[5..7].parse().map_err(|_| invalid())?;
+    let day: u8 = value[8..].parse().map_err(|_| invalid())?;
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return Err(invalid()),
+    };
+    if day == 0 || day > days {
+        return Err(invalid());
+    }
+    Ok((year, month, day))
+}
+
+fn parse_optional_date(value: &str) -> Result<Option<Ymd>, String> {
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        parse_date(value).map(Some)
+    }
+}
+
+fn parse_row(line: usize, text: &str) -> Result<Row<'_>, String> {
+    let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+    let [prefix, operator, effective_from, effective_to, source, date] = fields[..] else {
+        return Err(format!("expected 6 fields, found {}", fields.len()));
+    };
+
+    if !(4..=5).contains(&prefix.len())
+        || !prefix.starts_with('0')
+        || !prefix.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(format!("malformed prefix {:?}", prefix));
+    }
+    let operator = OPERATOR_NAMES
+        .iter()
+        .position(|name| *name == operator)
+        .ok_or_else(|| format!("unknown operator {:?}", operator))?;
+    let effective_from = parse_optional_date(effective_from)?;
+    let effective_to = parse_optional_date(effective_to)?;
+    if let (Some(from), Some(to)) = (effective_from, effective_to) {
+        if from >= to {
+            return Err(String::from("effective_to must be after effective_from"));
+        }
+    }
+    if source.is_empty() || source.contains('"') {
+        return Err(format!("missing or malformed source {:?}", source));
+    }
+
+    Ok(Row {
+        line,
+        prefix,
+        operator,
+        effective_from,
+        effective_to,
+        source,
+        date: parse_date(date)?,
+    })
+}
+
+/// Parses and checks a whole dataset. Errors carry the 1 based line number.
+pub(crate) fn parse_rows(text: &str) -> Result<Vec<Row<'_>>, (usize, String)> {
+    let mut rows: Vec<Row<'_>> = Vec::new();
+    let mut header = false;
+    for (index, text) in text.lines().enumerate() {
+        let line = index + 1;
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        if !header {
+            if text != HEADER {
+                return Err((line, format!("unexpected header, expected {:?}", HEADER)));
+            }
+            header = true;
+            continue;
+        }
+
+        let row = parse_row(line, text).map_err(|e| (line, e))?;
+        if let Some(other) = rows
+            .iter()
+            .find(|other| other.prefix == row.prefix && other.overlaps(&row))
+        {
+            return Err((
+                line,
+                format!(
+                    "prefix {} overlaps the assignment on line {}",
+                    row.prefix, other.line
+                ),
+            ));
+        }
+        rows.push(row);
+    }
+
+    if !header {
+        return Err((0, String::from("missing header")));
+    }
+    Ok(rows)
+}
@@ -0,0 +1,165 @@
+use core::{fmt, str::FromStr};
+
+use crate::errors::MobileNetworkError;
+
+/// A calendar date, used for the effective dates of prefix assignments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub(crate) year: u16,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Date {
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Today's date in Philippine time (UTC+8). On wasm32-unknown-unknown the clock
+    /// is read from the JavaScript host.
+    #[cfg(feature = "std")]
+    pub fn today() -> Self {
+        Self::from_days_since_epoch(((unix_seconds() + 8 * 3600) / 86_400) as i64)
+    }
+
+    // Howard Hinnant's `civil_from_days`.
+    #[cfg(feature = "std")]
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as u16;
+        Self { year, month, day }
+    }
+}
+
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+fn unix_seconds() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// `SystemTime::now` panics on this target.
+#[cfg(all(feature = "std", target_arch = "wasm32", target_os = "unknown"))]
+fn unix_seconds() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+/// Parses a fixed width field of ASCII digits. `str::parse` alone would also accept a
+/// sign, as in `+024`.
+fn field<T: FromStr>(digits: &str) -> Option<T> {
+    if digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl FromStr for Date {
+    type Err = MobileNetworkError;
+
+    /// Parses `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || MobileNetworkError::InvalidDate(s.into());
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+        let year = field(&s[..4]).ok_or_else(invalid)?;
+        let month = field(&s[5..7]).ok_or_else(invalid)?;
+        let day = field(&s[8..]).ok_or_else(invalid)?;
+        Date::new(year, month, day).ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn parses_and_displays() {
+        let date: Date = "2024-02-29".parse().unwrap();
+        assert_eq!(date, Date::new(2024, 2, 29).unwrap());
+        assert_eq!(date.to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn is_err_on_invalid_dates() {
+        for input in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-1-01",
+            "20240101",
+            "2024-01-00",
+            "+024-01-01",
+            "2024-+1-01",
+            "2024-01-+1",
+        ] {
+            assert!(input.parse::<Date>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn orders_chronologically() {
+        assert!(Date::new(2023, 12, 31).unwrap() < Date::new(2024, 1, 1).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_days_since_epoch() {
+        assert_eq!(
+            Date::from_days_since_epoch(0),
+            Date::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(19_782),
+            Date::new(2024, 2, 29).unwrap()
+        );
+    }
+}
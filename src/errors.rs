@@ -1,5 +1,6 @@
-use std::error::Error;
-use std::fmt;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
 
 #[derive(Debug)]
 pub enum MobileNetworkError {
@@ -8,6 +9,31 @@ pub enum MobileNetworkError {
     UnrecognizedPrefix(String),
     RegexError(String), // To handle regex compilation errors
     MutexError(String), // To handle mutex lock errors
+    InvalidDate(String),
+    InvalidDataset(String),
+    ResolverError(String),
+    OperatorMismatch(String),
+    UnknownOperator(String),
+    UnicodeDigit(String),
+}
+
+impl MobileNetworkError {
+    /// The name of the variant, e.g. `InvalidLength`, for grouping errors in reports.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            MobileNetworkError::InvalidLength => "InvalidLength",
+            MobileNetworkError::NonNumeric => "NonNumeric",
+            MobileNetworkError::UnrecognizedPrefix(_) => "UnrecognizedPrefix",
+            MobileNetworkError::RegexError(_) => "RegexError",
+            MobileNetworkError::MutexError(_) => "MutexError",
+            MobileNetworkError::InvalidDate(_) => "InvalidDate",
+            MobileNetworkError::InvalidDataset(_) => "InvalidDataset",
+            MobileNetworkError::ResolverError(_) => "ResolverError",
+            MobileNetworkError::OperatorMismatch(_) => "OperatorMismatch",
+            MobileNetworkError::UnknownOperator(_) => "UnknownOperator",
+            MobileNetworkError::UnicodeDigit(_) => "UnicodeDigit",
+        }
+    }
 }
 
 impl fmt::Display for MobileNetworkError {
@@ -25,6 +51,16 @@ impl fmt::Display for MobileNetworkError {
             }
             MobileNetworkError::RegexError(text) => write!(f, "Regex error {}", text),
             MobileNetworkError::MutexError(text) => write!(f, "Mutex lock error {}", text),
+            MobileNetworkError::InvalidDate(text) => {
+                write!(f, "Invalid date {}, expected YYYY-MM-DD", text)
+            }
+            MobileNetworkError::InvalidDataset(text) => write!(f, "Invalid dataset {}", text),
+            MobileNetworkError::ResolverError(text) => write!(f, "Resolver error {}", text),
+            MobileNetworkError::OperatorMismatch(text) => write!(f, "Operator mismatch: {}", text),
+            MobileNetworkError::UnknownOperator(text) => write!(f, "Unknown operator: {}", text),
+            MobileNetworkError::UnicodeDigit(text) => {
+                write!(f, "Non-ASCII digit {}; only 0-9 are accepted", text)
+            }
         }
     }
 }
@@ -0,0 +1,301 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::{classify::classify, format::normalize, operator::Operator};
+
+/// A mobile number found in free text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberMatch<'a> {
+    /// Byte range of the number in the scanned text.
+    pub span: Range<usize>,
+    /// The number as written, e.g. `+63 917 123 4567`.
+    pub text: &'a str,
+    /// The number in national form, e.g. `09171234567`.
+    pub number: String,
+    /// `None` when the number is well formed but its prefix is not assigned.
+    pub operator: Option<Operator>,
+}
+
+/// Separators allowed between the digit groups of one number.
+const SEPARATORS: &[u8] = b" -.()";
+
+/// Most digits any spelling `normalize` accepts has, `639171234567`. Candidates stop
+/// growing past it, so each group is normalized a bounded number of times and long
+/// runs of short groups such as `1 1 1 …` are scanned in linear time.
+const MAX_DIGITS: usize = 12;
+
+/// Finds mobile numbers in `text`, such as support tickets or chat logs, in the order
+/// they appear:
+///
+/// ```
+/// use ph_mobile_network::extract::extract;
+///
+/// let found = extract("text me at 0917-123-4567 or +63 998 765 4321");
+/// assert_eq!(found[0].number, "09171234567");
+/// assert_eq!(found[1].text, "+63 998 765 4321");
+/// ```
+///
+/// A number is a run of digit groups joined by up to two of ` -.()`, optionally
+/// starting with `+` or `(`, in any form `normalize` accepts. Runs touching letters
+/// or digits are skipped, so nothing is found inside longer digit strings such as
+/// `1234509171234567` or `ID09171234567`; a last group running into a word, as in
+/// `0917 123 4567 3pm`, is left out of the numbers before it. Groups joined by a
+/// space may be split into separate numbers, as in `0917 123 4567 0918 765 4321`;
+/// groups joined by `-` or `.` are never split.
+pub fn extract(text: &str) -> Vec<NumberMatch<'_>> {
+    let bytes = text.as_bytes();
+    let mut found = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let Some(run) = run_at(bytes, pos) else {
+            pos += 1;
+            continue;
+        };
+        let end = run.groups.last().map_or(pos, |group| group.end);
+        found.extend(run.numbers(text));
+        pos = end.max(pos + 1);
+    }
+
+    found
+}
+
+/// Digit groups and the separators between them, starting at a word boundary.
+struct Run {
+    /// Start of the run, including a leading `+` or `(`.
+    start: usize,
+    groups: Vec<Range<usize>>,
+    /// Whether each group may start or end a number: it follows or precedes a space
+    /// (or the edge of the run).
+    opens: Vec<bool>,
+    closes: Vec<bool>,
+}
+
+fn run_at(bytes: &[u8], start: usize) -> Option<Run> {
+    // A leading `+` or `(` is a boundary itself, so `a+639171234567` keeps its sign.
+    if start > 0 && bytes[start].is_ascii_digit() && bytes[start - 1].is_ascii_alphanumeric() {
+        return None;
+    }
+
+    let mut pos = start;
+    if bytes.get(pos) == Some(&b'(') {
+        pos += 1;
+    }
+    if bytes.get(pos) == Some(&b'+') {
+        pos += 1;
+    }
+    if !bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let mut run = Run {
+        start,
+        groups: Vec::new(),
+        opens: Vec::new(),
+        closes: Vec::new(),
+    };
+    let mut opens = true;
+    loop {
+        let group_start = pos;
+        while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+            pos += 1;
+        }
+        run.groups.push(group_start..pos);
+        run.opens.push(opens);
+
+        let separator = bytes[pos..]
+            .iter()
+            .take(2)
+            .take_while(|b| SEPARATORS.contains(b))
+            .count();
+        let continues = separator > 0 && bytes.get(pos + separator).is_some_and(u8::is_ascii_digit);
+        if !continues {
+            // A group running into a word, e.g. `09171234567abc`, ends no number.
+            run.closes
+                .push(!bytes.get(pos).is_some_and(u8::is_ascii_alphabetic));
+            return Some(run);
+        }
+
+        opens = bytes[pos..pos + separator].contains(&b' ');
+        run.closes.push(opens);
+        pos += separator;
+    }
+}
+
+impl Run {
+    /// The numbers in the run: from each group that may open a number, the longest
+    /// stretch of groups that normalizes, continuing after it.
+    fn numbers<'a>(&self, text: &'a str) -> Vec<NumberMatch<'a>> {
+        let mut numbers = Vec::new();
+        let mut first = 0;
+
+        while first < self.groups.len() {
+            if !self.opens[first] {
+                first += 1;
+                continue;
+            }
+            let start = if first == 0 {
+                self.start
+            } else {
+                self.groups[first].start
+            };
+
+            let mut digits = 0;
+            let window = self.groups[first..]
+                .iter()
+                .take_while(|group| {
+                    digits += group.len();
+                    digits <= MAX_DIGITS
+                })
+                .count();
+
+            let found = (first..first + window)
+                .rev()
+                .filter(|&last| self.closes[last])
+                .find_map(|last| {
+                    let span = start..self.groups[last].end;
+                    let number = normalize(&text[span.clone()]).ok()?;
+                    Some((last, span, number))
+                });
+
+            match found {
+                Some((last, span, number)) => {
+                    numbers.push(NumberMatch {
+                        text: &text[span.clone()],
+                        operator: classify(&number).ok(),
+                        number,
+                        span,
+                    });
+                    first = last + 1;
+                }
+                None => first += 1,
+            }
+        }
+
+        numbers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(text: &str) -> Vec<String> {
+        extract(text)
+            .into_iter()
+            .map(|found| found.number)
+            .collect()
+    }
+
+    #[test]
+    fn finds_numbers_with_spans() {
+        let text = "text me at 0917-123-4567 or +63 998 765 4321.";
+        let found = extract(text);
+        assert_eq!(found.len(), 2);
+
+        assert_eq!(found[0].span, 11..24);
+        assert_eq!(found[0].text, "0917-123-4567");
+        assert_eq!(found[0].number, "09171234567");
+        assert_eq!(found[0].operator, Some(Operator::Globe));
+
+        assert_eq!(&text[found[1].span.clone()], "+63 998 765 4321");
+        assert_eq!(found[1].number, "09987654321");
+        assert_eq!(found[1].operator, Some(Operator::Smart));
+    }
+
+    #[test]
+    fn accepts_common_spellings() {
+        assert_eq!(
+            numbers("(0917) 123 4567, 639181234567 and 9191234567"),
+            ["09171234567", "09181234567", "09191234567"]
+        );
+        assert_eq!(numbers("call 0917.123.4567!"), ["09171234567"]);
+        assert_eq!(
+            numbers("0917 123 4567 0918 765 4321"),
+            ["09171234567", "09187654321"]
+        );
+        assert_eq!(numbers("ref 2024 0917 123 4567"), ["09171234567"]);
+    }
+
+    #[test]
+    fn skips_longer_digit_runs_and_words() {
+        assert!(numbers("order 1234509171234567").is_empty());
+        assert!(numbers("091712345678").is_empty());
+        assert!(numbers("0917-123-4567-89").is_empty());
+        assert!(numbers("ID09171234567 or 09171234567abc").is_empty());
+        assert!(numbers("on 2024-01-15 at 10.30").is_empty());
+    }
+
+    #[test]
+    fn keeps_numbers_before_a_group_touching_a_word() {
+        assert_eq!(numbers("call 0917 123 4567 3pm"), ["09171234567"]);
+        assert_eq!(numbers("0917 123 4567 2x daily"), ["09171234567"]);
+        assert_eq!(numbers("0917 123 4567 0918 765 4321abc"), ["09171234567"]);
+        assert!(numbers("0917 123 4567abc").is_empty());
+    }
+
+    #[test]
+    fn keeps_the_sign_after_a_letter() {
+        let text = "a+639171234567";
+        let found = extract(text);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].span, 1..text.len());
+        assert_eq!(found[0].text, "+639171234567");
+        assert_eq!(found[0].number, "09171234567");
+    }
+
+    #[test]
+    fn reports_unassigned_prefixes() {
+        let found = extract("try 0800 123 4567");
+        assert_eq!(found[0].number, "08001234567");
+        assert_eq!(found[0].operator, None);
+    }
+
+    #[test]
+    fn spans_are_byte_offsets() {
+        let text = "Tawag: ０ or 0917 123 4567 ✓";
+        let found = extract(text);
+        assert_eq!(found.len(), 1);
+        assert_eq!(&text[found[0].span.clone()], "0917 123 4567");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn scans_long_runs_in_linear_time() {
+        let text = "1 ".repeat(10_000);
+        let started = std::time::Instant::now();
+        assert!(extract(&text).is_empty());
+        let text = format!("{}0917 123 4567", "1-".repeat(10_000));
+        assert!(extract(&text).is_empty());
+        let text = format!("{}0917 123 4567", "1 ".repeat(10_000));
+        assert_eq!(numbers(&text), ["09171234567"]);
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    mod properties {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn spans_point_at_the_numbers(text in any::<String>()) {
+                for found in extract(&text) {
+                    prop_assert_eq!(&text[found.span.clone()], found.text);
+                    prop_assert_eq!(normalize(found.text).unwrap(), found.number);
+                }
+            }
+
+            #[test]
+            fn finds_numbers_between_words(
+                before in "[a-z ]{0,10}",
+                number in "0[89][0-9]{9}",
+                after in "[a-z ,.]{0,10}",
+            ) {
+                let text = format!("{} {} {}", before, number, after);
+                prop_assert_eq!(numbers(&text), [number]);
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! C ABI for the library. Every function takes NUL terminated UTF-8 strings and
+//! reports failures through [`PhStatus`]; strings are written into buffers owned
+//! by the caller. The header lives in `include/ph_mobile_network.h` and is
+//! regenerated with `cbindgen --config cbindgen.toml --output include/ph_mobile_network.h`.
+
+use std::ffi::{c_char, CStr};
+
+use crate::{
+    classify::classify,
+    errors::MobileNetworkError,
+    format::{self, NumberFormat},
+    mobile_network::MobileNetwork,
+    operator::Operator,
+    validate::Validate,
+};
+
+/// Buffer size large enough for any string written by this library, including the NUL.
+pub const PH_BUFFER_LEN: usize = 32;
+
+/// Stable operator codes. Values never change once released.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhOperator {
+    Unknown = 0,
+    Globe = 1,
+    Smart = 2,
+    Sun = 3,
+    Tnt = 4,
+    Dito = 5,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhStatus {
+    Ok = 0,
+    InvalidLength = 1,
+    NonNumeric = 2,
+    UnrecognizedPrefix = 3,
+    RegexError = 4,
+    MutexError = 5,
+    InvalidDate = 6,
+    InvalidDataset = 7,
+    ResolverError = 8,
+    OperatorMismatch = 9,
+    UnknownOperator = 10,
+    UnicodeDigit = 11,
+    NullPointer = 100,
+    InvalidUtf8 = 101,
+    BufferTooSmall = 102,
+    InvalidArgument = 103,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhFormat {
+    National = 0,
+    International = 1,
+    E164 = 2,
+}
+
+impl From<&MobileNetworkError> for PhStatus {
+    fn from(error: &MobileNetworkError) -> Self {
+        match error {
+            MobileNetworkError::InvalidLength => PhStatus::InvalidLength,
+            MobileNetworkError::NonNumeric => PhStatus::NonNumeric,
+            MobileNetworkError::UnrecognizedPrefix(_) => PhStatus::UnrecognizedPrefix,
+            MobileNetworkError::RegexError(_) => PhStatus::RegexError,
+            MobileNetworkError::MutexError(_) => PhStatus::MutexError,
+            MobileNetworkError::InvalidDate(_) => PhStatus::InvalidDate,
+            MobileNetworkError::InvalidDataset(_) => PhStatus::InvalidDataset,
+            MobileNetworkError::ResolverError(_) => PhStatus::ResolverError,
+            MobileNetworkError::OperatorMismatch(_) => PhStatus::OperatorMismatch,
+            MobileNetworkError::UnknownOperator(_) => PhStatus::UnknownOperator,
+            MobileNetworkError::UnicodeDigit(_) => PhStatus::UnicodeDigit,
+        }
+    }
+}
+
+impl From<Operator> for PhOperator {
+    fn from(operator: Operator) -> Self {
+        match operator {
+            Operator::Globe => PhOperator::Globe,
+            Operator::Smart => PhOperator::Smart,
+            Operator::Sun => PhOperator::Sun,
+            Operator::Tnt => PhOperator::Tnt,
+            Operator::Dito => PhOperator::Dito,
+        }
+    }
+}
+
+impl From<&MobileNetwork> for PhOperator {
+    fn from(network: &MobileNetwork) -> Self {
+        match network {
+            MobileNetwork::Globe(_) => PhOperator::Globe,
+            MobileNetwork::Smart(_) => PhOperator::Smart,
+            MobileNetwork::Sun(_) => PhOperator::Sun,
+            MobileNetwork::TNT(_) => PhOperator::Tnt,
+            MobileNetwork::Dito(_) => PhOperator::Dito,
+            MobileNetwork::Invalid(_) => PhOperator::Unknown,
+        }
+    }
+}
+
+/// Enums arrive from C as plain integers, which may hold any value, so functions
+/// take them as `u32` and convert with these checked conversions.
+impl TryFrom<u32> for PhOperator {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, ()> {
+        Ok(match value {
+            0 => PhOperator::Unknown,
+            1 => PhOperator::Globe,
+            2 => PhOperator::Smart,
+            3 => PhOperator::Sun,
+            4 => PhOperator::Tnt,
+            5 => PhOperator::Dito,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for PhStatus {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, ()> {
+        Ok(match value {
+            0 => PhStatus::Ok,
+            1 => PhStatus::InvalidLength,
+            2 => PhStatus::NonNumeric,
+            3 => PhStatus::UnrecognizedPrefix,
+            4 => PhStatus::RegexError,
+            5 => PhStatus::MutexError,
+            6 => PhStatus::InvalidDate,
+            7 => PhStatus::InvalidDataset,
+            8 => PhStatus::ResolverError,
+            9 => PhStatus::OperatorMismatch,
+            10 => PhStatus::UnknownOperator,
+            11 => PhStatus::UnicodeDigit,
+            100 => PhStatus::NullPointer,
+            101 => PhStatus::InvalidUtf8,
+            102 => PhStatus::BufferTooSmall,
+            103 => PhStatus::InvalidArgument,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for PhFormat {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, ()> {
+        Ok(match value {
+            0 => PhFormat::National,
+            1 => PhFormat::International,
+            2 => PhFormat::E164,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl From<PhFormat> for NumberFormat {
+    fn from(format: PhFormat) -> Self {
+        match format {
+            PhFormat::National => NumberFormat::National,
+            PhFormat::International => NumberFormat::International,
+            PhFormat::E164 => NumberFormat::E164,
+        }
+    }
+}
+
+unsafe fn read_str<'a>(number: *const c_char) -> Result<&'a str, PhStatus> {
+    if number.is_null() {
+        return Err(PhStatus::NullPointer);
+    }
+    CStr::from_ptr(number)
+        .to_str()
+        .map_err(|_| PhStatus::InvalidUtf8)
+}
+
+unsafe fn write_str(value: &str, buffer: *mut c_char, buffer_len: usize) -> PhStatus {
+    if buffer.is_null() {
+        return PhStatus::NullPointer;
+    }
+    if value.len() >= buffer_len {
+        return PhStatus::BufferTooSmall;
+    }
+    std::ptr::copy_nonoverlapping(value.as_ptr(), buffer.cast::<u8>(), value.len());
+    *buffer.add(value.len()) = 0;
+    PhStatus::Ok
+}
+
+fn status_of<T>(result: Result<T, MobileNetworkError>) -> Result<T, PhStatus> {
+    result.map_err(|e| PhStatus::from(&e))
+}
+
+/// Looks up the operator of `number` and stores it in `out_operator`. Uses the
+/// lock-free `classify`, so it can be called from any number of threads at once.
+///
+/// # Safety
+///
+/// `number` must be a valid NUL terminated string and `out_operator` must point to
+/// writable memory for one `PhOperator`.
+#[no_mangle]
+pub unsafe extern "C" fn ph_operator_of(
+    number: *const c_char,
+    out_operator: *mut PhOperator,
+) -> PhStatus {
+    if out_operator.is_null() {
+        return PhStatus::NullPointer;
+    }
+    let operator = match read_str(number).and_then(|n| status_of(classify(n))) {
+        Ok(operator) => operator,
+        Err(status) => return status,
+    };
+    *out_operator = PhOperator::from(operator);
+    PhStatus::Ok
+}
+
+/// Returns `PH_STATUS_OK` when `number` is a valid mobile number of its operator.
+///
+/// # Safety
+///
+/// `number` must be a valid NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ph_validate(number: *const c_char) -> PhStatus {
+    let result = read_str(number)
+        .and_then(|n| status_of(classify(n).and_then(|operator| operator.validate(n))));
+    match result {
+        Ok(_) => PhStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Writes the 11 digit national form of `number` into `buffer`.
+///
+/// # Safety
+///
+/// `number` must be a valid NUL terminated string and `buffer` must point to
+/// at least `buffer_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ph_parse(
+    number: *const c_char,
+    buffer: *mut c_char,
+    buffer_len: usize,
+) -> PhStatus {
+    match read_str(number).and_then(|n| status_of(format::normalize(n))) {
+        Ok(national) => write_str(&national, buffer, buffer_len),
+        Err(status) => status,
+    }
+}
+
+/// Writes `number` in the requested `format`, a `PhFormat` value, into `buffer`.
+/// Returns `PH_STATUS_INVALID_ARGUMENT` for any other value.
+///
+/// # Safety
+///
+/// `number` must be a valid NUL terminated string and `buffer` must point to
+/// at least `buffer_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ph_format(
+    number: *const c_char,
+    format: u32,
+    buffer: *mut c_char,
+    buffer_len: usize,
+) -> PhStatus {
+    let Ok(format) = PhFormat::try_from(format) else {
+        return PhStatus::InvalidArgument;
+    };
+    match read_str(number).and_then(|n| status_of(format::format(n, format.into()))) {
+        Ok(formatted) => write_str(&formatted, buffer, buffer_len),
+        Err(status) => status,
+    }
+}
+
+/// Returns the lowercase name of `operator`, a `PhOperator` value, as a static NUL
+/// terminated string; `"unknown"` for any other value.
+#[no_mangle]
+pub extern "C" fn ph_operator_name(operator: u32) -> *const c_char {
+    let Ok(operator) = PhOperator::try_from(operator) else {
+        return c"unknown".as_ptr();
+    };
+    let name: &'static CStr = match operator {
+        PhOperator::Unknown => c"unknown",
+        PhOperator::Globe => c"globe",
+        PhOperator::Smart => c"smart",
+        PhOperator::Sun => c"sun",
+        PhOperator::Tnt => c"tnt",
+        PhOperator::Dito => c"dito",
+    };
+    name.as_ptr()
+}
+
+/// Returns a static NUL terminated description of `status`, a `PhStatus` value;
+/// `"unknown"` for any other value.
+#[no_mangle]
+pub extern "C" fn ph_status_message(status: u32) -> *const c_char {
+    let Ok(status) = PhStatus::try_from(status) else {
+        return c"unknown".as_ptr();
+    };
+    let message: &'static CStr = match status {
+        PhStatus::Ok => c"ok",
+        PhStatus::InvalidLength => c"invalid phone number length",
+        PhStatus::NonNumeric => c"mobile number contains non-numeric characters",
+        PhStatus::UnrecognizedPrefix => c"unrecognized mobile number prefix",
+        PhStatus::RegexError => c"regex error",
+        PhStatus::MutexError => c"mutex lock error",
+        PhStatus::InvalidDate => c"invalid date, expected YYYY-MM-DD",
+        PhStatus::InvalidDataset => c"invalid prefix dataset",
+        PhStatus::ResolverError => c"resolver error",
+        PhStatus::OperatorMismatch => c"number belongs to a different operator",
+        PhStatus::UnknownOperator => c"unknown operator name",
+        PhStatus::UnicodeDigit => c"number contains a non-ASCII digit",
+        PhStatus::NullPointer => c"null pointer argument",
+        PhStatus::InvalidUtf8 => c"input is not valid UTF-8",
+        PhStatus::BufferTooSmall => c"output buffer too small",
+        PhStatus::InvalidArgument => c"argument out of range",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+
+    #[test]
+    fn operator_of_globe() {
+        let number = CString::new("09171234567").unwrap();
+        let mut operator = PhOperator::Unknown;
+        let status = unsafe { ph_operator_of(number.as_ptr(), &mut operator) };
+        assert_eq!(status, PhStatus::Ok);
+        assert_eq!(operator, PhOperator::Globe);
+    }
+
+    #[test]
+    fn operator_of_uses_longest_prefix() {
+        let number = CString::new("09253123456").unwrap();
+        let mut operator = PhOperator::Unknown;
+        let status = unsafe { ph_operator_of(number.as_ptr(), &mut operator) };
+        assert_eq!(status, PhStatus::Ok);
+        assert_eq!(operator, PhOperator::Globe);
+        assert_eq!(unsafe { ph_validate(number.as_ptr()) }, PhStatus::Ok);
+    }
+
+    #[test]
+    fn is_ok_from_many_threads() {
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    let number = CString::new("09171234567").unwrap();
+                    for _ in 0..2_000 {
+                        let mut operator = PhOperator::Unknown;
+                        let status = unsafe { ph_operator_of(number.as_ptr(), &mut operator) };
+                        assert_eq!((status, operator), (PhStatus::Ok, PhOperator::Globe));
+                        assert_eq!(unsafe { ph_validate(number.as_ptr()) }, PhStatus::Ok);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn operator_codes_match_rust_codes() {
+        for operator in Operator::iter() {
+            assert_eq!(PhOperator::from(operator) as u8, operator.code());
+        }
+    }
+
+    #[test]
+    fn validate_maps_errors() {
+        let number = CString::new("0917123").unwrap();
+        assert_eq!(
+            unsafe { ph_validate(number.as_ptr()) },
+            PhStatus::InvalidLength
+        );
+        assert_eq!(
+            unsafe { ph_validate(std::ptr::null()) },
+            PhStatus::NullPointer
+        );
+        let number = CString::new("０９１７１２３４５６７").unwrap();
+        assert_eq!(
+            unsafe { ph_validate(number.as_ptr()) },
+            PhStatus::UnicodeDigit
+        );
+    }
+
+    #[test]
+    fn format_into_caller_buffer() {
+        let number = CString::new("09171234567").unwrap();
+        let mut buffer = [0 as c_char; PH_BUFFER_LEN];
+        let status = unsafe {
+            ph_format(
+                number.as_ptr(),
+                PhFormat::E164 as u32,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+            )
+        };
+        assert_eq!(status, PhStatus::Ok);
+        let formatted = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(formatted.to_str().unwrap(), "+639171234567");
+    }
+
+    #[test]
+    fn rejects_out_of_range_enums() {
+        let number = CString::new("09171234567").unwrap();
+        let mut buffer = [0 as c_char; PH_BUFFER_LEN];
+        let status = unsafe { ph_format(number.as_ptr(), 7, buffer.as_mut_ptr(), buffer.len()) };
+        assert_eq!(status, PhStatus::InvalidArgument);
+
+        let name = unsafe { CStr::from_ptr(ph_operator_name(99)) };
+        assert_eq!(name.to_str().unwrap(), "unknown");
+        let message = unsafe { CStr::from_ptr(ph_status_message(u32::MAX)) };
+        assert_eq!(message.to_str().unwrap(), "unknown");
+        let message = unsafe { CStr::from_ptr(ph_status_message(PhStatus::Ok as u32)) };
+        assert_eq!(message.to_str().unwrap(), "ok");
+    }
+
+    #[test]
+    fn is_err_on_small_buffer() {
+        let number = CString::new("+63 917 123 4567").unwrap();
+        let mut buffer = [0 as c_char; 11];
+        let status = unsafe { ph_parse(number.as_ptr(), buffer.as_mut_ptr(), buffer.len()) };
+        assert_eq!(status, PhStatus::BufferTooSmall);
+    }
+}
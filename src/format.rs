@@ -0,0 +1,187 @@
+use alloc::{format, string::String};
+
+use crate::{errors::MobileNetworkError, numerals::DigitPolicy};
+
+/// Output styles supported by [`format`].
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// `09171234567`
+    National,
+    /// `+63 917 123 4567`
+    International,
+    /// `+639171234567`
+    E164,
+}
+
+/// Converts a mobile number written as `09171234567`, `9171234567`, `639171234567`
+/// or `+639171234567` (spaces, dashes, dots and parentheses allowed) into the
+/// 11 digit national form used by `MobileNetwork::get`.
+pub fn normalize(number: &str) -> Result<String, MobileNetworkError> {
+    normalize_with(number, DigitPolicy::Reject)
+}
+
+/// Like [`normalize`], applying `digits` to decimal digits of other scripts first, so
+/// `DigitPolicy::Fold` turns `+６３ ９１７ １２３ ４５６７` into `09171234567`.
+pub fn normalize_with(number: &str, digits: DigitPolicy) -> Result<String, MobileNetworkError> {
+    let number = digits.apply(number)?;
+    let trimmed = number.trim();
+    let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+
+    let mut digits = String::with_capacity(unsigned.len());
+    for c in unsigned.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' | '(' | ')' => continue,
+            _ => return Err(MobileNetworkError::NonNumeric),
+        }
+    }
+
+    let subscriber = match digits.len() {
+        12 if digits.starts_with("63") => &digits[2..],
+        11 if digits.starts_with('0') => &digits[1..],
+        10 => &digits[..],
+        _ => return Err(MobileNetworkError::InvalidLength),
+    };
+
+    if !subscriber.starts_with(['8', '9']) {
+        return Err(MobileNetworkError::UnrecognizedPrefix(digits));
+    }
+
+    Ok(format!("0{}", subscriber))
+}
+
+pub fn format(number: &str, format: NumberFormat) -> Result<String, MobileNetworkError> {
+    let national = normalize(number)?;
+    let subscriber = &national[1..];
+
+    Ok(match format {
+        NumberFormat::National => format!("0{}", subscriber),
+        NumberFormat::International => format!(
+            "+63 {} {} {}",
+            &subscriber[..3],
+            &subscriber[3..6],
+            &subscriber[6..]
+        ),
+        NumberFormat::E164 => format!("+63{}", subscriber),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_common_spellings() {
+        for input in [
+            "09171234567",
+            "9171234567",
+            "639171234567",
+            "+639171234567",
+            "+63 917 123 4567",
+            "0917-123-4567",
+            "(0917) 123.4567",
+        ] {
+            assert_eq!(normalize(input).unwrap(), "09171234567", "{}", input);
+        }
+    }
+
+    #[test]
+    fn is_err_for_non_numeric() {
+        assert!(matches!(
+            normalize("0917abc4567"),
+            Err(MobileNetworkError::NonNumeric)
+        ));
+    }
+
+    #[test]
+    fn is_err_on_invalid_length() {
+        assert!(matches!(
+            normalize("091712345"),
+            Err(MobileNetworkError::InvalidLength)
+        ));
+        assert!(matches!(
+            normalize("0639171234567"),
+            Err(MobileNetworkError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn is_err_on_landline() {
+        assert!(matches!(
+            normalize("0281234567"),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_or_folds_unicode_digits() {
+        assert!(matches!(
+            normalize("０９１７ １２３ ４５６７"),
+            Err(MobileNetworkError::UnicodeDigit(_))
+        ));
+        assert_eq!(
+            normalize_with("+６３ ９１７ １２３ ４５６７", DigitPolicy::Fold).unwrap(),
+            "09171234567"
+        );
+        assert_eq!(
+            normalize_with("٠٩١٧-١٢٣-٤٥٦٧", DigitPolicy::Fold).unwrap(),
+            "09171234567"
+        );
+        assert!(matches!(
+            normalize_with("0917 123 4567", DigitPolicy::Reject),
+            Ok(number) if number == "09171234567"
+        ));
+    }
+
+    #[test]
+    fn formats_all_styles() {
+        let number = "0917 123 4567";
+        assert_eq!(
+            format(number, NumberFormat::National).unwrap(),
+            "09171234567"
+        );
+        assert_eq!(
+            format(number, NumberFormat::International).unwrap(),
+            "+63 917 123 4567"
+        );
+        assert_eq!(format(number, NumberFormat::E164).unwrap(), "+639171234567");
+    }
+
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    mod properties {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        fn any_format() -> impl Strategy<Value = NumberFormat> {
+            prop_oneof![
+                Just(NumberFormat::National),
+                Just(NumberFormat::International),
+                Just(NumberFormat::E164),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn normalize_never_panics(input in any::<String>()) {
+                let _ = normalize(&input);
+            }
+
+            #[test]
+            fn normalize_is_idempotent(input in "[+]?[0-9 ().-]{0,16}") {
+                if let Ok(national) = normalize(&input) {
+                    prop_assert_eq!(national.len(), 11);
+                    prop_assert_eq!(normalize(&national).unwrap(), national);
+                }
+            }
+
+            #[test]
+            fn format_round_trips(subscriber in "[89][0-9]{9}", style in any_format()) {
+                let national = format!("0{}", subscriber);
+                let formatted = format(&national, style).unwrap();
+                prop_assert_eq!(normalize(&formatted).unwrap(), national);
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Quickcheck [`Arbitrary`] wrappers built on [`NumberGenerator`].
+use quickcheck::{Arbitrary, Gen};
+
+use super::{Invalid, NumberGenerator};
+
+/// A valid number of any operator, in national format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyValidNumber(pub String);
+
+/// A number that fails validation in any of the [`Invalid`] ways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyInvalidNumber(pub String);
+
+impl Arbitrary for AnyValidNumber {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let number = NumberGenerator::seeded(u64::arbitrary(g))
+            .any()
+            .expect("the bundled registry has prefixes");
+        Self(number)
+    }
+}
+
+impl Arbitrary for AnyInvalidNumber {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let kind = *g.choose(&Invalid::ALL).unwrap_or(&Invalid::TooShort);
+        Self(NumberGenerator::seeded(u64::arbitrary(g)).invalid_of(kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::*;
+    use crate::classify::classify;
+
+    quickcheck! {
+        fn valid_numbers_classify(number: AnyValidNumber) -> bool {
+            classify(&number.0).is_ok()
+        }
+
+        fn invalid_numbers_fail(number: AnyInvalidNumber) -> bool {
+            classify(&number.0).is_err()
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    errors::MobileNetworkError,
+    format::{format, NumberFormat},
+    operator::Operator,
+    registry::{NumberRange, Registry},
+};
+
+#[cfg(feature = "quickcheck")]
+pub mod arbitrary;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+/// Ways [`NumberGenerator::invalid`] breaks a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invalid {
+    /// One to nine digits. Ten digits are not used, since `normalize` reads them as
+    /// a number without its leading `0`.
+    TooShort,
+    /// Twelve to fifteen digits, never `63` followed by ten digits.
+    TooLong,
+    /// Eleven characters with a letter or symbol among the digits, never one of the
+    /// separators `normalize` strips.
+    NonNumeric,
+    /// Eleven digits under a prefix no operator has.
+    UnassignedPrefix,
+}
+
+impl Invalid {
+    pub const ALL: [Invalid; 4] = [
+        Invalid::TooShort,
+        Invalid::TooLong,
+        Invalid::NonNumeric,
+        Invalid::UnassignedPrefix,
+    ];
+}
+
+/// Produces random numbers that pass (or deliberately fail) validation against a
+/// [`Registry`], for tests and fixtures. Seed it to get the same numbers every run.
+#[derive(Debug, Clone)]
+pub struct NumberGenerator<R = StdRng> {
+    rng: R,
+    registry: Registry,
+    format: NumberFormat,
+}
+
+impl NumberGenerator<StdRng> {
+    /// A generator seeded from the operating system.
+    pub fn new() -> Self {
+        Self::from_rng(StdRng::from_os_rng())
+    }
+
+    /// A generator that produces the same numbers for the same `seed`.
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for NumberGenerator<StdRng> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Rng> NumberGenerator<R> {
+    pub fn from_rng(rng: R) -> Self {
+        Self {
+            rng,
+            registry: Registry::bundled(),
+            format: NumberFormat::National,
+        }
+    }
+
+    /// Generates numbers from the prefixes of `registry` instead of the bundled ones.
+    pub fn with_registry(mut self, registry: Registry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Writes generated numbers in `format`; national (`09171234567`) by default.
+    pub fn with_format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// A valid number of any operator.
+    pub fn any(&mut self) -> Result<String, MobileNetworkError> {
+        let ranges = Operator::iter()
+            .flat_map(|operator| self.registry.ranges_of(operator))
+            .collect();
+        self.pick(ranges, "")
+    }
+
+    /// A valid number of `operator`. Brands parse to the operator whose prefixes they
+    /// use, so `"tm".parse()?` gives Globe numbers.
+    pub fn for_operator(&mut self, operator: Operator) -> Result<String, MobileNetworkError> {
+        let ranges = self.registry.ranges_of(operator);
+        self.pick(ranges, operator.name())
+    }
+
+    /// A valid number starting with `prefix`, which may be shorter or longer than the
+    /// assigned prefixes, e.g. `09` or `0917123`.
+    pub fn for_prefix(&mut self, prefix: &str) -> Result<String, MobileNetworkError> {
+        let ranges = Operator::iter()
+            .flat_map(|operator| self.registry.ranges_of(operator))
+            .filter_map(|range| within(&range, prefix))
+            .collect();
+        self.pick(ranges, prefix)
+    }
+
+    /// A number that is invalid in a random one of the [`Invalid`] ways.
+    pub fn invalid(&mut self) -> String {
+        let kind = Invalid::ALL[self.rng.random_range(0..Invalid::ALL.len())];
+        self.invalid_of(kind)
+    }
+
+    /// A number that is invalid in the way given by `kind`. Invalid numbers are not
+    /// formatted, since formatting would reject them.
+    pub fn invalid_of(&mut self, kind: Invalid) -> String {
+        match kind {
+            Invalid::TooShort => {
+                let len = self.rng.random_range(1..=9);
+                self.digits(len)
+            }
+            Invalid::TooLong => loop {
+                let len = self.rng.random_range(12..=15);
+                let number = self.digits(len);
+                if !(len == 12 && number.starts_with("63")) {
+                    break number;
+                }
+            },
+            Invalid::NonNumeric => {
+                // No `-`, `.`, `(`, `)` or space, which `normalize` accepts as
+                // separators; `+` is only accepted in front.
+                const REJECTED: &[u8] = b"abcxyz+#*/";
+                let mut number: Vec<u8> = self.digits(11).into_bytes();
+                let at = self.rng.random_range(1..number.len());
+                number[at] = REJECTED[self.rng.random_range(0..REJECTED.len())];
+                String::from_utf8(number).unwrap_or_default()
+            }
+            Invalid::UnassignedPrefix => loop {
+                let number = format!("0{}", self.digits(10));
+                if matches!(
+                    self.registry.classify(&number),
+                    Err(MobileNetworkError::UnrecognizedPrefix(_))
+                ) {
+                    break number;
+                }
+            },
+        }
+    }
+
+    fn digits(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| char::from(b'0' + self.rng.random_range(0..10)))
+            .collect()
+    }
+
+    /// Picks a number uniformly from all numbers of `ranges`.
+    fn pick(
+        &mut self,
+        ranges: Vec<NumberRange>,
+        wanted: &str,
+    ) -> Result<String, MobileNetworkError> {
+        let total: u64 = ranges.iter().map(NumberRange::len).sum();
+        if total == 0 {
+            return Err(MobileNetworkError::UnrecognizedPrefix(wanted.to_string()));
+        }
+
+        let mut index = self.rng.random_range(0..total);
+        for range in &ranges {
+            if index < range.len() {
+                let number = range.get(index).unwrap_or_default();
+                return format(&number, self.format);
+            }
+            index -= range.len();
+        }
+        unreachable!("index is below the total of all ranges")
+    }
+}
+
+/// The part of `range` whose numbers start with `prefix`, if any.
+fn within(range: &NumberRange, prefix: &str) -> Option<NumberRange> {
+    if range.prefix().starts_with(prefix) {
+        return Some(range.clone());
+    }
+    let carved_out = range
+        .excluded()
+        .iter()
+        .any(|excluded| prefix.starts_with(excluded.as_str()));
+    if !prefix.starts_with(range.prefix()) || carved_out || prefix.len() > 11 {
+        return None;
+    }
+    Some(NumberRange::new(
+        prefix,
+        range.operator(),
+        range.excluded().iter().map(String::as_str),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{classify::classify, format::normalize, validate::Validate};
+
+    #[test]
+    fn is_reproducible_with_a_seed() {
+        let numbers = |seed| {
+            let mut generator = NumberGenerator::seeded(seed);
+            (0..5).map(|_| generator.any().unwrap()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn generates_valid_numbers_for_operator() {
+        let mut generator = NumberGenerator::seeded(1);
+        for operator in Operator::iter() {
+            for _ in 0..50 {
+                let number = generator.for_operator(operator).unwrap();
+                assert_eq!(operator.validate(&number).unwrap().operator(), operator);
+            }
+        }
+    }
+
+    #[test]
+    fn generates_numbers_under_prefix() {
+        let mut generator = NumberGenerator::seeded(2);
+        for _ in 0..50 {
+            let number = generator.for_prefix("0925").unwrap();
+            assert!(number.starts_with("0925"));
+            classify(&number).unwrap();
+        }
+        assert!(generator
+            .for_prefix("0917123")
+            .unwrap()
+            .starts_with("0917123"));
+        assert_eq!(
+            classify(&generator.for_prefix("09253").unwrap()).unwrap(),
+            Operator::Globe
+        );
+        assert!(generator.for_prefix("0000").is_err());
+    }
+
+    #[test]
+    fn formats_numbers() {
+        let mut generator = NumberGenerator::seeded(3).with_format(NumberFormat::E164);
+        let number = generator.for_operator(Operator::Dito).unwrap();
+        assert!(number.starts_with("+63"));
+        assert_eq!(
+            classify(&normalize(&number).unwrap()).unwrap(),
+            Operator::Dito
+        );
+    }
+
+    #[test]
+    fn generates_invalid_numbers() {
+        let mut generator = NumberGenerator::seeded(4);
+        for _ in 0..100 {
+            let number = generator.invalid();
+            assert!(classify(&number).is_err(), "{}", number);
+        }
+        assert!(matches!(
+            classify(&generator.invalid_of(Invalid::NonNumeric)),
+            Err(MobileNetworkError::NonNumeric)
+        ));
+    }
+
+    #[test]
+    fn invalid_numbers_stay_invalid_after_normalizing() {
+        let mut generator = NumberGenerator::seeded(5);
+        for kind in Invalid::ALL {
+            for _ in 0..500 {
+                let number = generator.invalid_of(kind);
+                assert!(
+                    normalize(&number).and_then(|n| classify(&n)).is_err(),
+                    "{:?}: {}",
+                    kind,
+                    number
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Proptest strategies built on [`NumberGenerator`]. Each case seeds its own
+//! generator, so shrinking moves between numbers rather than mangling digits.
+use proptest::prelude::*;
+
+use super::{Invalid, NumberGenerator};
+use crate::operator::Operator;
+
+/// Valid numbers of any operator, in national format.
+pub fn valid_number() -> impl Strategy<Value = String> {
+    any::<u64>().prop_map(|seed| {
+        NumberGenerator::seeded(seed)
+            .any()
+            .expect("the bundled registry has prefixes")
+    })
+}
+
+/// Valid numbers of `operator`, in national format.
+pub fn valid_number_for(operator: Operator) -> impl Strategy<Value = String> {
+    any::<u64>().prop_map(move |seed| {
+        NumberGenerator::seeded(seed)
+            .for_operator(operator)
+            .expect("every operator has bundled prefixes")
+    })
+}
+
+/// Numbers that fail validation in any of the [`Invalid`] ways.
+pub fn invalid_number() -> impl Strategy<Value = String> {
+    (0..Invalid::ALL.len(), any::<u64>())
+        .prop_map(|(kind, seed)| NumberGenerator::seeded(seed).invalid_of(Invalid::ALL[kind]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{classify::classify, validate::Validate};
+
+    proptest! {
+        #[test]
+        fn valid_numbers_classify(number in valid_number()) {
+            prop_assert!(classify(&number).is_ok());
+        }
+
+        #[test]
+        fn operator_numbers_validate(number in valid_number_for(Operator::Smart)) {
+            prop_assert!(Operator::Smart.validate(&number).is_ok());
+        }
+
+        #[test]
+        fn invalid_numbers_fail(number in invalid_number()) {
+            prop_assert!(classify(&number).is_err());
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use lazy_static::lazy_static;
 use std::sync::{Arc, Mutex};
 
+use crate::{date::Date, globals::prefix_table::prefixes_as_of, operator::Operator};
+
 lazy_static! {
-    pub(crate) static ref DITO_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(vec![
-        "0895", "0896", "0897", "0898", "0991", "0992", "0993", "0994",
-    ]));
+    pub(crate) static ref DITO_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(
+        prefixes_as_of(Operator::Dito, Date::today()).collect()
+    ));
 }
 
 #[cfg(test)]
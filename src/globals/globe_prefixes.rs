@@ -1,13 +1,12 @@
 use lazy_static::lazy_static;
 use std::sync::{Arc, Mutex};
 
+use crate::{date::Date, globals::prefix_table::prefixes_as_of, operator::Operator};
+
 lazy_static! {
-    pub(crate) static ref GLOBE_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(vec![
-        "0817", "0904", "0905", "0906", "0915", "0916", "0917", "0926", "0927", "0935", "0936",
-        "0937", "0945", "0954", "0955", "0956", "0965", "0966", "0967", "0975", "0976", "0977",
-        "0978", "0979", "0995", "0996", "0997", "09173", "09175", "09176", "09178", "09253",
-        "09255", "09256", "09257", "09258",
-    ]));
+    pub(crate) static ref GLOBE_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(
+        prefixes_as_of(Operator::Globe, Date::today()).collect()
+    ));
 }
 
 #[cfg(test)]
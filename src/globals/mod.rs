@@ -1,5 +1,11 @@
+#[cfg(feature = "std")]
 pub(crate) mod dito_prefixes;
+#[cfg(feature = "std")]
 pub(crate) mod globe_prefixes;
+pub mod prefix_table;
+#[cfg(feature = "std")]
 pub(crate) mod smart_prefixes;
+#[cfg(feature = "std")]
 pub(crate) mod sun_prefixes;
+#[cfg(feature = "std")]
 pub(crate) mod tnt_prefixes;
@@ -0,0 +1,96 @@
+use crate::{date::Date, operator::Operator};
+
+/// One prefix assignment from `data/prefixes.csv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixRecord {
+    pub prefix: &'static str,
+    pub operator: Operator,
+    /// First day the assignment applies, `None` if it predates the dataset.
+    pub effective_from: Option<Date>,
+    /// First day the assignment no longer applies, `None` while it is current.
+    pub effective_to: Option<Date>,
+    /// Where the assignment was taken from.
+    pub source: &'static str,
+    /// When the assignment was recorded.
+    pub date: Date,
+}
+
+impl PrefixRecord {
+    pub fn is_effective_on(&self, date: Date) -> bool {
+        self.effective_from.is_none_or(|from| from <= date)
+            && self.effective_to.is_none_or(|to| date < to)
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.effective_to.is_none()
+    }
+}
+
+// Generated by build.rs from data/prefixes.csv. The mutable `*_PREFIXES` lists
+// start out as the assignments effective today, while `classify` reads the table
+// directly so it works without `std`.
+include!(concat!(env!("OUT_DIR"), "/prefix_table.rs"));
+
+pub fn prefixes_as_of(operator: Operator, date: Date) -> impl Iterator<Item = &'static str> {
+    PREFIX_TABLE
+        .iter()
+        .filter(move |record| record.operator == operator && record.is_effective_on(date))
+        .map(|record| record.prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(from: Option<Date>, to: Option<Date>) -> PrefixRecord {
+        PrefixRecord {
+            prefix: "0917",
+            operator: Operator::Globe,
+            effective_from: from,
+            effective_to: to,
+            source: "test",
+            date: Date::new(2024, 1, 1).unwrap(),
+        }
+    }
+
+    #[test]
+    fn table_is_generated_from_dataset() {
+        let record = PREFIX_TABLE
+            .iter()
+            .find(|record| record.prefix == "0817")
+            .unwrap();
+        assert_eq!(record.operator, Operator::Globe);
+        assert!(!record.source.is_empty());
+        assert!(record.is_current());
+    }
+
+    #[test]
+    fn effective_range_is_half_open() {
+        let from = Date::new(2020, 1, 1).unwrap();
+        let to = Date::new(2022, 1, 1).unwrap();
+        let record = record(Some(from), Some(to));
+
+        assert!(!record.is_effective_on(Date::new(2019, 12, 31).unwrap()));
+        assert!(record.is_effective_on(from));
+        assert!(record.is_effective_on(Date::new(2021, 12, 31).unwrap()));
+        assert!(!record.is_effective_on(to));
+        assert!(!record.is_current());
+    }
+
+    #[test]
+    fn open_ended_record_is_always_effective() {
+        let record = record(None, None);
+        assert!(record.is_effective_on(Date::new(1990, 1, 1).unwrap()));
+        assert!(record.is_effective_on(Date::new(2090, 1, 1).unwrap()));
+    }
+
+    #[test]
+    fn prefixes_as_of_filters_by_operator() {
+        let dito: alloc::vec::Vec<_> =
+            prefixes_as_of(Operator::Dito, Date::new(2024, 1, 1).unwrap()).collect();
+        assert_eq!(
+            dito,
+            ["0895", "0896", "0897", "0898", "0991", "0992", "0993", "0994"]
+        );
+    }
+}
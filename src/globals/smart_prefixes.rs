@@ -1,12 +1,12 @@
 use lazy_static::lazy_static;
 use std::sync::{Arc, Mutex};
 
+use crate::{date::Date, globals::prefix_table::prefixes_as_of, operator::Operator};
+
 lazy_static! {
-    pub(crate) static ref SMART_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(vec![
-        "0813", "0908", "0911", "0913", "0914", "0919", "0920", "0921", "0928", "0929", "0939",
-        "0946", "0947", "0949", "0951", "0961", "0963", "0968", "0969", "0970", "0981", "0998",
-        "0999", "0960",
-    ]));
+    pub(crate) static ref SMART_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(
+        prefixes_as_of(Operator::Smart, Date::today()).collect()
+    ));
 }
 
 #[cfg(test)]
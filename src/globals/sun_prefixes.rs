@@ -1,11 +1,12 @@
 use lazy_static::lazy_static;
 use std::sync::{Arc, Mutex};
 
+use crate::{date::Date, globals::prefix_table::prefixes_as_of, operator::Operator};
+
 lazy_static! {
-    pub(crate) static ref SUN_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(vec![
-        "0922", "0923", "0924", "0925", "0931", "0932", "0933", "0934", "0940", "0941", "0942",
-        "0943", "0944", "0973", "0974",
-    ]));
+    pub(crate) static ref SUN_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(
+        prefixes_as_of(Operator::Sun, Date::today()).collect()
+    ));
 }
 
 #[cfg(test)]
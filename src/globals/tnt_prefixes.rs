@@ -2,11 +2,12 @@ use std::sync::{Arc, Mutex};
 
 use lazy_static::lazy_static;
 
+use crate::{date::Date, globals::prefix_table::prefixes_as_of, operator::Operator};
+
 lazy_static! {
-    pub(crate) static ref TNT_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(vec![
-        "0907", "0909", "0910", "0912", "0918", "0930", "0938", "0946", "0948", "0950", "0963",
-        "0989", "0998",
-    ]));
+    pub(crate) static ref TNT_PREFIXES: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(
+        prefixes_as_of(Operator::Tnt, Date::today()).collect()
+    ));
 }
 
 #[cfg(test)]
@@ -1,6 +1,11 @@
 use regex::Regex;
 
-use crate::{errors::MobileNetworkError, pattern::generate_pattern, validate::Validate};
+use crate::{
+    errors::MobileNetworkError,
+    operator::Operator,
+    pattern::generate_pattern,
+    validate::{digits, ValidNumber, Validate},
+};
 
 pub struct Globe(regex::Regex);
 
@@ -14,17 +19,12 @@ impl Globe {
 }
 
 impl Validate for Globe {
-    fn validate(&self, number: &str) -> Result<bool, MobileNetworkError> {
-        if !number.chars().all(char::is_numeric) {
-            return Err(MobileNetworkError::NonNumeric);
-        }
-        if number.len() != 11 {
-            return Err(MobileNetworkError::InvalidLength);
-        }
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
+        let number = digits(number)?;
         if !self.0.is_match(number) {
             return Err(MobileNetworkError::UnrecognizedPrefix(number.to_owned()));
         }
-        Ok(true)
+        Ok(ValidNumber::new(number, Operator::Globe))
     }
 }
 
@@ -46,7 +46,17 @@ mod tests {
         let prefixes = ["0917", "0925"];
         let result = Globe::new(&prefixes).unwrap();
         // exact length and prefix  and is a valid number
-        assert!(result.validate("09171234567").unwrap());
+        assert_eq!(
+            result.validate("09171234567").unwrap().operator(),
+            Operator::Globe
+        );
+    }
+
+    #[test]
+    fn is_valid_for_five_digit_prefix() {
+        let result = Globe::new(&["0917", "09253"]).unwrap();
+        assert!(result.validate("09253123456").is_ok());
+        assert!(result.validate("09251234567").is_err());
     }
 
     #[test]
@@ -1,11 +1,50 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod classify;
+pub mod dataset;
+pub mod date;
+#[cfg(feature = "std")]
 pub(crate) mod dito;
 pub mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod extract;
+pub mod format;
+#[cfg(feature = "generate")]
+pub mod generate;
 pub mod globals;
+#[cfg(feature = "std")]
 pub(crate) mod globe;
+pub mod mask;
+#[cfg(feature = "std")]
 pub mod mobile_network;
+#[cfg(feature = "std")]
 pub mod mutate;
+pub mod number_set;
+pub mod numerals;
+pub mod operator;
+pub mod operator_info;
+pub mod operator_set;
+pub mod packed;
+#[cfg(feature = "std")]
 pub(crate) mod pattern;
+#[cfg(feature = "pseudonym")]
+pub mod pseudonym;
+#[cfg(feature = "python")]
+mod python;
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod resolver;
+#[cfg(feature = "std")]
 pub(crate) mod smart;
+#[cfg(feature = "std")]
 pub(crate) mod sun;
+pub mod stats;
+pub mod suppression;
+#[cfg(feature = "std")]
 pub(crate) mod talk_n_text;
 pub mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,313 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::Range;
+
+use crate::{
+    classify::classify, errors::MobileNetworkError, format::normalize, numerals,
+    validate::ValidNumber,
+};
+
+/// Which digits of a number stay readable when it is masked for logs.
+///
+/// The default keeps the prefix, so the operator can still be told apart, and the
+/// last two digits: `0917 *** **67`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mask {
+    keep_first: usize,
+    keep_last: usize,
+    symbol: char,
+    grouped: bool,
+}
+
+impl Mask {
+    /// Keeps the prefix and the last two digits: `0917 *** **67`.
+    pub const fn new() -> Self {
+        Self {
+            keep_first: 4,
+            keep_last: 2,
+            symbol: '*',
+            grouped: true,
+        }
+    }
+
+    /// Keeps only the prefix: `0917 *** ****`.
+    pub const fn keep_prefix() -> Self {
+        Self::new().with_last(0)
+    }
+
+    /// Keeps only the last `n` digits: `**** *** *567` for 3.
+    pub const fn keep_last(n: usize) -> Self {
+        Self::new().with_first(0).with_last(n)
+    }
+
+    /// Hides every digit: `**** *** ****`.
+    pub const fn full() -> Self {
+        Self::new().with_first(0).with_last(0)
+    }
+
+    /// Keeps the first `n` digits.
+    pub const fn with_first(mut self, n: usize) -> Self {
+        self.keep_first = n;
+        self
+    }
+
+    /// Keeps the last `n` digits.
+    pub const fn with_last(mut self, n: usize) -> Self {
+        self.keep_last = n;
+        self
+    }
+
+    /// Replaces hidden digits with `symbol` instead of `*`.
+    pub const fn with_symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Whether to split the output into `0917 *** **67` groups (the default) or
+    /// keep it as one run, `0917*****67`.
+    pub const fn grouped(mut self, grouped: bool) -> Self {
+        self.grouped = grouped;
+        self
+    }
+
+    /// Masks a number in any format `normalize` accepts.
+    pub fn apply(&self, number: &str) -> Result<String, MobileNetworkError> {
+        Ok(self.apply_national(&normalize(number)?))
+    }
+
+    /// Masks an 11 digit national number.
+    fn apply_national(&self, national: &str) -> String {
+        let len = national.len();
+        let mut masked = String::with_capacity(len + 2);
+        for (i, digit) in national.chars().enumerate() {
+            if self.grouped && (i == 4 || i == 7) {
+                masked.push(' ');
+            }
+            if i < self.keep_first || i + self.keep_last >= len {
+                masked.push(digit);
+            } else {
+                masked.push(self.symbol);
+            }
+        }
+        masked
+    }
+}
+
+impl Default for Mask {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValidNumber {
+    /// The number masked by `mask`, e.g. `0917 *** **67`, safe to log next to
+    /// [`operator`](ValidNumber::operator).
+    pub fn masked(&self, mask: Mask) -> String {
+        mask.apply_national(self.as_str())
+    }
+}
+
+/// What [`redact`] puts in place of each number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redaction {
+    /// The number masked, e.g. `0917 *** **67`.
+    Mask(Mask),
+    /// Only the operator, e.g. `[globe]`, or `[mobile]` for unassigned prefixes.
+    Operator,
+}
+
+/// Replaces every mobile number in `text`, so log lines and support tickets can be
+/// stored without them:
+///
+/// ```
+/// use ph_mobile_network::mask::{redact, Mask, Redaction};
+///
+/// let line = "sms to +63 917 123 4567 failed";
+/// assert_eq!(redact(line, Redaction::Mask(Mask::new())), "sms to 0917 *** **67 failed");
+/// assert_eq!(redact(line, Redaction::Operator), "sms to [globe] failed");
+/// ```
+///
+/// Unlike [`extract`](crate::extract::extract), which avoids false positives, this
+/// errs on the side of hiding: any 10 to 12 digits joined by separators that
+/// normalize to a mobile number are replaced, even inside words or longer digit
+/// strings, so `tel09171234567` becomes `tel0917 *** **67`. Digits of other scripts,
+/// such as full-width `０９１７…`, are recognised too. For tokens instead of
+/// masks, see `Pseudonymizer::redact` with the `pseudonym` feature.
+pub fn redact(text: &str, redaction: Redaction) -> String {
+    replace_numbers(text, |national| match redaction {
+        Redaction::Mask(mask) => mask.apply_national(national),
+        Redaction::Operator => {
+            let name = classify(national).map_or("mobile", |operator| operator.name());
+            alloc::format!("[{}]", name)
+        }
+    })
+}
+
+/// Replaces every number [`find_numbers`] finds with `replacement` of its national
+/// form.
+pub(crate) fn replace_numbers(text: &str, mut replacement: impl FnMut(&str) -> String) -> String {
+    let mut replaced = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (span, national) in find_numbers(text) {
+        replaced.push_str(&text[copied..span.start]);
+        replaced.push_str(&replacement(&national));
+        copied = span.end;
+    }
+    replaced.push_str(&text[copied..]);
+    replaced
+}
+
+/// Byte spans of 10 to 12 digits that normalize to a mobile number, with their
+/// national form, ignoring word boundaries. Digits of other scripts count as their
+/// ASCII digit, as with [`numerals::fold`]. Candidates made of whole digit groups are
+/// taken first, so in `9 09171234567` the number is not read as ten digits from the
+/// `9`; what is left is then scanned for numbers inside longer runs. Longer spellings
+/// are tried first, and a `+` or `(` right before the first digit is replaced along
+/// with the number.
+fn find_numbers(text: &str) -> Vec<(Range<usize>, String)> {
+    let digits: Vec<(Range<usize>, char)> = text
+        .char_indices()
+        .filter_map(|(at, c)| {
+            let digit = if c.is_ascii_digit() {
+                Some(c)
+            } else {
+                numerals::to_ascii_digit(c)
+            };
+            digit.map(|digit| (at..at + c.len_utf8(), digit))
+        })
+        .collect();
+    let between = |i: usize| &text[digits[i].0.end..digits[i + 1].0.start];
+    // Whether the digits at `i` and `i + 1` are joined by at most two separators.
+    let joined = |i: usize| {
+        let gap = between(i);
+        gap.len() <= 2 && gap.bytes().all(|b| b" -.()".contains(&b))
+    };
+    let starts_group = |i: usize| i == 0 || !between(i - 1).is_empty();
+    let ends_group = |i: usize| i + 1 == digits.len() || !between(i).is_empty();
+
+    let mut taken = vec![false; digits.len()];
+    let mut found = Vec::new();
+    for whole_groups in [true, false] {
+        let mut i = 0;
+        'digits: while i < digits.len() {
+            for len in [12, 11, 10] {
+                let last = i + len - 1;
+                if last >= digits.len()
+                    || (whole_groups && !(starts_group(i) && ends_group(last)))
+                    || taken[i..=last].contains(&true)
+                    || !(i..last).all(joined)
+                {
+                    continue;
+                }
+                let candidate: String = digits[i..=last].iter().map(|(_, digit)| digit).collect();
+                if let Ok(national) = normalize(&candidate) {
+                    let mut start = digits[i].0.start;
+                    for symbol in [b'+', b'('] {
+                        if start > 0 && text.as_bytes()[start - 1] == symbol {
+                            start -= 1;
+                        }
+                    }
+                    found.push((start..digits[last].0.end, national));
+                    taken[i..=last].fill(true);
+                    i += len;
+                    continue 'digits;
+                }
+            }
+            i += 1;
+        }
+    }
+    found.sort_by_key(|(span, _)| span.start);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operator::Operator, validate::Validate};
+
+    #[test]
+    fn masks_with_policies() {
+        let number = "+63 917 123 4567";
+        assert_eq!(Mask::new().apply(number).unwrap(), "0917 *** **67");
+        assert_eq!(Mask::keep_prefix().apply(number).unwrap(), "0917 *** ****");
+        assert_eq!(Mask::keep_last(3).apply(number).unwrap(), "**** *** *567");
+        assert_eq!(Mask::full().apply(number).unwrap(), "**** *** ****");
+        assert_eq!(
+            Mask::new()
+                .with_symbol('x')
+                .grouped(false)
+                .apply(number)
+                .unwrap(),
+            "0917xxxxx67"
+        );
+        assert_eq!(
+            Mask::new().with_first(11).apply(number).unwrap(),
+            "0917 123 4567"
+        );
+        assert!(Mask::new().apply("0917").is_err());
+    }
+
+    #[test]
+    fn masks_valid_numbers() {
+        let valid = Operator::Globe.validate("09171234567").unwrap();
+        assert_eq!(valid.masked(Mask::new()), "0917 *** **67");
+        assert_eq!(valid.masked(Mask::keep_last(4)), "**** *** 4567");
+    }
+
+    #[test]
+    fn redacts_numbers_touching_letters_and_digits() {
+        let mask = Redaction::Mask(Mask::new());
+        assert_eq!(redact("tel09171234567", mask), "tel0917 *** **67");
+        assert_eq!(redact("09171234567abc", mask), "0917 *** **67abc");
+        assert_eq!(redact("0917-123-4567-89 ", mask), "0917 *** **67-89 ");
+        assert_eq!(
+            redact("order 1234509171234567", Redaction::Operator),
+            "order 12345[globe]"
+        );
+        assert_eq!(
+            redact("(0917) 123 4567 or (+63) 918 765 4321", Redaction::Operator),
+            "[globe] or [tnt]"
+        );
+        assert_eq!(
+            redact("on 2024-01-15 at 10.30, ref 123456", mask),
+            "on 2024-01-15 at 10.30, ref 123456"
+        );
+    }
+
+    #[test]
+    fn redacts_unicode_digits() {
+        assert_eq!(
+            redact("call ０９１７１２３４５６７ now", Redaction::Operator),
+            "call [globe] now"
+        );
+        assert_eq!(
+            redact("tawag ٠٩١٨-١٢٣-٤٥٦٧", Redaction::Mask(Mask::new())),
+            "tawag 0918 *** **67"
+        );
+    }
+
+    #[test]
+    fn prefers_whole_digit_groups() {
+        assert_eq!(
+            redact("x 9 09171234567", Redaction::Operator),
+            "x 9 [globe]"
+        );
+        assert_eq!(
+            redact("ref 12 0917 123 4567", Redaction::Mask(Mask::new())),
+            "ref 12 0917 *** **67"
+        );
+    }
+
+    #[test]
+    fn redacts_every_number() {
+        let text = "call 0917-123-4567 or 0800 123 4567, ref 2024";
+        assert_eq!(
+            redact(text, Redaction::Mask(Mask::keep_prefix())),
+            "call 0917 *** **** or 0800 *** ****, ref 2024"
+        );
+        assert_eq!(
+            redact(text, Redaction::Operator),
+            "call [globe] or [mobile], ref 2024"
+        );
+        assert_eq!(redact("no numbers", Redaction::Operator), "no numbers");
+    }
+}
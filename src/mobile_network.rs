@@ -1,17 +1,21 @@
 use std::fmt;
 
 use crate::{
+    classify::{classify_as_of, longest_match},
+    date::Date,
     dito::Dito,
     errors::MobileNetworkError,
     globals::{
-        dito_prefixes::DITO_PREFIXES, globe_prefixes::GLOBE_PREFIXES,
+        dito_prefixes::DITO_PREFIXES, globe_prefixes::GLOBE_PREFIXES, prefix_table::prefixes_as_of,
         smart_prefixes::SMART_PREFIXES, sun_prefixes::SUN_PREFIXES, tnt_prefixes::TNT_PREFIXES,
     },
     globe::Globe,
+    numerals,
+    operator::Operator,
     smart::Smart,
     sun::Sun,
     talk_n_text::TNT,
-    validate::Validate,
+    validate::{ValidNumber, Validate},
 };
 
 pub enum MobileNetwork {
@@ -24,8 +28,11 @@ pub enum MobileNetwork {
 }
 
 impl MobileNetwork {
+    /// Finds the network of `number` in the mutable prefix lists. Like `classify`,
+    /// the longest matching prefix wins, so five digit blocks such as Globe's `09253`
+    /// inside Sun's `0925` are honoured.
     pub fn get(number: &str) -> Result<Self, MobileNetworkError> {
-        let prefix = &number[..number.len().min(4)];
+        numerals::reject(number)?;
 
         let globe_prefixes = GLOBE_PREFIXES.try_lock().map_err(|_| {
             MobileNetworkError::MutexError("Failed to lock GLOBE_PREFIXES".to_string())
@@ -47,30 +54,74 @@ impl MobileNetwork {
             MobileNetworkError::MutexError("Failed to lock DITO_PREFIXES".to_string())
         })?;
 
-        if globe_prefixes.contains(&prefix) {
-            Globe::new(&globe_prefixes).map(MobileNetwork::Globe)
-        } else if smart_prefixes.contains(&prefix) {
-            Smart::new(&smart_prefixes).map(MobileNetwork::Smart)
-        } else if sun_prefixes.contains(&prefix) {
-            Sun::new(&sun_prefixes).map(MobileNetwork::Sun)
-        } else if talk_n_text_prefixes.contains(&prefix) {
-            TNT::new(&talk_n_text_prefixes).map(MobileNetwork::TNT)
-        } else if dito_prefixes.contains(&prefix) {
-            Dito::new(&dito_prefixes).map(MobileNetwork::Dito)
-        } else {
-            Err(MobileNetworkError::UnrecognizedPrefix(prefix.to_string()))
+        let lists = [
+            (Operator::Globe, &globe_prefixes),
+            (Operator::Smart, &smart_prefixes),
+            (Operator::Sun, &sun_prefixes),
+            (Operator::Tnt, &talk_n_text_prefixes),
+            (Operator::Dito, &dito_prefixes),
+        ];
+        let operator = longest_match(
+            number,
+            lists.iter().flat_map(|(operator, prefixes)| {
+                prefixes.iter().map(move |prefix| (*prefix, *operator))
+            }),
+        )?;
+        let prefixes = match operator {
+            Operator::Globe => &globe_prefixes,
+            Operator::Smart => &smart_prefixes,
+            Operator::Sun => &sun_prefixes,
+            Operator::Tnt => &talk_n_text_prefixes,
+            Operator::Dito => &dito_prefixes,
+        };
+        Self::with_prefixes(operator, prefixes)
+    }
+
+    /// Like `get`, but uses the bundled assignments that were effective on `date`
+    /// instead of the mutable prefix lists, e.g. to settle a dispute about an old charge.
+    ///
+    /// The operator is found like `classify_as_of` does, so five digit blocks such
+    /// as Globe's `09253` inside Sun's `0925` are honoured.
+    pub fn get_as_of(number: &str, date: Date) -> Result<Self, MobileNetworkError> {
+        let operator = classify_as_of(number, date)?;
+        let prefixes: Vec<&str> = prefixes_as_of(operator, date).collect();
+        Self::with_prefixes(operator, &prefixes)
+    }
+
+    /// The operator of this network, or `None` for `Invalid`.
+    pub fn operator(&self) -> Option<Operator> {
+        match self {
+            MobileNetwork::Globe(_) => Some(Operator::Globe),
+            MobileNetwork::Smart(_) => Some(Operator::Smart),
+            MobileNetwork::Sun(_) => Some(Operator::Sun),
+            MobileNetwork::TNT(_) => Some(Operator::Tnt),
+            MobileNetwork::Dito(_) => Some(Operator::Dito),
+            MobileNetwork::Invalid(_) => None,
+        }
+    }
+
+    pub(crate) fn with_prefixes(
+        operator: Operator,
+        prefixes: &[&str],
+    ) -> Result<Self, MobileNetworkError> {
+        match operator {
+            Operator::Globe => Globe::new(prefixes).map(MobileNetwork::Globe),
+            Operator::Smart => Smart::new(prefixes).map(MobileNetwork::Smart),
+            Operator::Sun => Sun::new(prefixes).map(MobileNetwork::Sun),
+            Operator::Tnt => TNT::new(prefixes).map(MobileNetwork::TNT),
+            Operator::Dito => Dito::new(prefixes).map(MobileNetwork::Dito),
         }
     }
 }
 
 impl Validate for MobileNetwork {
-    fn validate(&self, number: &str) -> Result<bool, crate::errors::MobileNetworkError> {
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
         match self {
-            MobileNetwork::Globe(globe) => globe.validate(number),
-            MobileNetwork::Smart(smart) => smart.validate(number),
-            MobileNetwork::Sun(sun) => sun.validate(number),
-            MobileNetwork::TNT(tnt) => tnt.validate(number),
-            MobileNetwork::Dito(dito) => dito.validate(number),
+            MobileNetwork::Globe(globe) => globe.validate_bytes(number),
+            MobileNetwork::Smart(smart) => smart.validate_bytes(number),
+            MobileNetwork::Sun(sun) => sun.validate_bytes(number),
+            MobileNetwork::TNT(tnt) => tnt.validate_bytes(number),
+            MobileNetwork::Dito(dito) => dito.validate_bytes(number),
             MobileNetwork::Invalid(text) => {
                 Err(MobileNetworkError::UnrecognizedPrefix(text.clone()))
             }
@@ -154,6 +205,14 @@ mod tests {
         assert!(invalid.validate("any_number").is_err());
     }
 
+    #[test]
+    fn operator_matches_network() {
+        setup();
+        let dito = MobileNetwork::get("08971234567").unwrap();
+        assert_eq!(dito.operator(), Some(Operator::Dito));
+        assert_eq!(MobileNetwork::Invalid("test".to_string()).operator(), None);
+    }
+
     #[test]
     fn test_get_globe_network() {
         setup();
@@ -189,6 +248,73 @@ mod tests {
         assert!(matches!(result, Ok(MobileNetwork::Dito(_))));
     }
 
+    #[test]
+    fn test_get_honours_five_digit_prefixes() {
+        setup();
+        append_globe_prefixes(&["09253"]);
+        append_sun_prefixes(&["0925"]);
+        let network = MobileNetwork::get("09253123456").unwrap();
+        assert!(matches!(network, MobileNetwork::Globe(_)));
+        assert_eq!(
+            network.validate("09253123456").unwrap().operator(),
+            Operator::Globe
+        );
+        assert!(matches!(
+            MobileNetwork::get("09251234567"),
+            Ok(MobileNetwork::Sun(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_as_of_uses_bundled_assignments() {
+        let date = Date::new(2024, 1, 1).unwrap();
+        let result = MobileNetwork::get_as_of("09171234567", date);
+        assert!(matches!(result, Ok(MobileNetwork::Globe(_))));
+        assert_eq!(
+            result.unwrap().validate("09171234567").unwrap().operator(),
+            Operator::Globe
+        );
+
+        let result = MobileNetwork::get_as_of("09871234567", date);
+        assert!(matches!(
+            result,
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_as_of_honours_five_digit_prefixes() {
+        let date = Date::new(2024, 1, 1).unwrap();
+        let network = MobileNetwork::get_as_of("09253123456", date).unwrap();
+        assert!(matches!(network, MobileNetwork::Globe(_)));
+        assert_eq!(
+            network.validate("09253123456").unwrap().operator(),
+            Operator::Globe
+        );
+        assert!(matches!(
+            MobileNetwork::get_as_of("09251234567", date),
+            Ok(MobileNetwork::Sun(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_as_of_follows_effective_dates() {
+        let before = Date::new(2021, 3, 7).unwrap();
+        let launch = Date::new(2021, 3, 8).unwrap();
+        assert!(matches!(
+            MobileNetwork::get_as_of("08971234567", before),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+        assert!(matches!(
+            MobileNetwork::get_as_of("08971234567", launch),
+            Ok(MobileNetwork::Dito(_))
+        ));
+        assert!(matches!(
+            MobileNetwork::get_as_of("09171234567", before),
+            Ok(MobileNetwork::Globe(_))
+        ));
+    }
+
     #[test]
     fn test_get_invalid_network() {
         setup();
@@ -198,4 +324,58 @@ mod tests {
             Err(MobileNetworkError::UnrecognizedPrefix(_))
         ));
     }
+
+    #[test]
+    fn test_get_rejects_multibyte_input() {
+        let date = Date::new(2024, 1, 1).unwrap();
+        for number in ["é0917123456", "091é1234567", "091١1234567", "091€"] {
+            assert!(MobileNetwork::get(number).is_err(), "{}", number);
+            assert!(
+                MobileNetwork::get_as_of(number, date).is_err(),
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_reports_unicode_digits() {
+        let date = Date::new(2024, 1, 1).unwrap();
+        for number in ["０９１７１２３４５６７", "٠٩١٧١٢٣٤٥٦٧"] {
+            assert!(matches!(
+                MobileNetwork::get(number),
+                Err(MobileNetworkError::UnicodeDigit(_))
+            ));
+            assert!(matches!(
+                MobileNetwork::get_as_of(number, date),
+                Err(MobileNetworkError::UnicodeDigit(_))
+            ));
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    mod properties {
+        use proptest::prelude::*;
+
+        use super::*;
+        use crate::classify::classify_as_of;
+
+        proptest! {
+            // The prefix lists are shared with other tests, so lookups may fail to
+            // lock; only the absence of panics is checked.
+            #[test]
+            fn get_never_panics(input in any::<String>()) {
+                let _ = MobileNetwork::get(&input);
+            }
+
+            #[test]
+            fn get_as_of_agrees_with_classify_as_of(number in "0[89][0-9]{9}") {
+                let date = Date::new(2024, 1, 1).unwrap();
+                let operator = MobileNetwork::get_as_of(&number, date)
+                    .and_then(|network| network.validate(&number))
+                    .map(|valid| valid.operator());
+                prop_assert_eq!(operator.ok(), classify_as_of(&number, date).ok());
+            }
+        }
+    }
 }
@@ -0,0 +1,356 @@
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    string::String,
+    vec::Vec,
+};
+
+use crate::{errors::MobileNetworkError, format::normalize, packed::PackedNumber};
+
+/// The key of `number`: its national form, packed. Unassigned prefixes are kept, so
+/// lists can be cleaned before the prefix table catches up.
+fn key(number: &str) -> Result<PackedNumber, MobileNetworkError> {
+    Ok(PackedNumber::from_national(&normalize(number)?))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry<V> {
+    value: V,
+    /// Every spelling inserted for the number, in insertion order.
+    spellings: Vec<String>,
+}
+
+/// A map keyed on numbers rather than their spelling: `0917 123 4567`,
+/// `+639171234567` and `9171234567` are the same key. Remembers how each number was
+/// written, to report duplicates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberMap<V> {
+    entries: BTreeMap<PackedNumber, Entry<V>>,
+}
+
+impl<V> NumberMap<V> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts `value` for `number`, returning the value it replaces.
+    pub fn insert(&mut self, number: &str, value: V) -> Result<Option<V>, MobileNetworkError> {
+        let key = key(number)?;
+        Ok(match self.entries.entry(key) {
+            btree_map::Entry::Occupied(mut occupied) => {
+                let entry = occupied.get_mut();
+                entry.spellings.push(number.into());
+                Some(core::mem::replace(&mut entry.value, value))
+            }
+            btree_map::Entry::Vacant(vacant) => {
+                vacant.insert(Entry {
+                    value,
+                    spellings: alloc::vec![number.into()],
+                });
+                None
+            }
+        })
+    }
+
+    /// The value for `number` in any spelling; `None` also for malformed numbers.
+    pub fn get(&self, number: &str) -> Option<&V> {
+        let key = key(number).ok()?;
+        self.entries.get(&key).map(|entry| &entry.value)
+    }
+
+    pub fn get_mut(&mut self, number: &str) -> Option<&mut V> {
+        let key = key(number).ok()?;
+        self.entries.get_mut(&key).map(|entry| &mut entry.value)
+    }
+
+    pub fn contains(&self, number: &str) -> bool {
+        self.get(number).is_some()
+    }
+
+    pub fn remove(&mut self, number: &str) -> Option<V> {
+        let key = key(number).ok()?;
+        self.entries.remove(&key).map(|entry| entry.value)
+    }
+
+    /// How `number` was written each time it was inserted.
+    pub fn spellings(&self, number: &str) -> Option<&[String]> {
+        let key = key(number).ok()?;
+        self.entries
+            .get(&key)
+            .map(|entry| entry.spellings.as_slice())
+    }
+
+    /// Numbers inserted more than once, with every spelling used.
+    pub fn duplicates(&self) -> impl Iterator<Item = (PackedNumber, &[String])> + '_ {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.spellings.len() > 1)
+            .map(|(&number, entry)| (number, entry.spellings.as_slice()))
+    }
+
+    /// Numbers and values in ascending order of number.
+    pub fn iter(&self) -> impl Iterator<Item = (PackedNumber, &V)> + '_ {
+        self.entries
+            .iter()
+            .map(|(&number, entry)| (number, &entry.value))
+    }
+
+    pub fn numbers(&self) -> impl Iterator<Item = PackedNumber> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// Keeps only the numbers for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(PackedNumber, &mut V) -> bool) {
+        self.entries
+            .retain(|&number, entry| keep(number, &mut entry.value));
+    }
+
+    /// Removes every number in `suppressed`, e.g. a do-not-contact list.
+    pub fn suppress(&mut self, suppressed: &NumberSet) {
+        self.retain(|number, _| !suppressed.contains_packed(number));
+    }
+}
+
+impl<V> Default for NumberMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A set of numbers that de-duplicates different spellings of the same number.
+///
+/// ```
+/// use ph_mobile_network::number_set::NumberSet;
+///
+/// let mut contacts = NumberSet::new();
+/// contacts.insert("0917 123 4567")?;
+/// contacts.insert("+639171234567")?;
+/// assert_eq!(contacts.len(), 1);
+/// assert_eq!(contacts.spellings("9171234567").unwrap().len(), 2);
+/// # Ok::<(), ph_mobile_network::errors::MobileNetworkError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberSet {
+    map: NumberMap<()>,
+}
+
+impl NumberSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Adds `number`, returning `false` if it was already in the set in any spelling.
+    pub fn insert(&mut self, number: &str) -> Result<bool, MobileNetworkError> {
+        Ok(self.map.insert(number, ())?.is_none())
+    }
+
+    /// Adds every number, returning those that could not be parsed with the reason.
+    pub fn insert_all<'a>(
+        &mut self,
+        numbers: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<(&'a str, MobileNetworkError)> {
+        numbers
+            .into_iter()
+            .filter_map(|number| self.insert(number).err().map(|error| (number, error)))
+            .collect()
+    }
+
+    pub fn contains(&self, number: &str) -> bool {
+        self.map.contains(number)
+    }
+
+    /// Like `contains` for a number that is already packed, without parsing.
+    pub fn contains_packed(&self, number: PackedNumber) -> bool {
+        self.map.entries.contains_key(&number)
+    }
+
+    pub fn remove(&mut self, number: &str) -> bool {
+        self.map.remove(number).is_some()
+    }
+
+    pub fn spellings(&self, number: &str) -> Option<&[String]> {
+        self.map.spellings(number)
+    }
+
+    pub fn duplicates(&self) -> impl Iterator<Item = (PackedNumber, &[String])> + '_ {
+        self.map.duplicates()
+    }
+
+    /// The numbers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = PackedNumber> + '_ {
+        self.map.numbers()
+    }
+
+    /// Numbers in either set, with the spellings of both.
+    pub fn union(&self, other: &NumberSet) -> NumberSet {
+        let mut union = self.clone();
+        for (&number, entry) in &other.map.entries {
+            union
+                .map
+                .entries
+                .entry(number)
+                .and_modify(|mine| mine.spellings.extend_from_slice(&entry.spellings))
+                .or_insert_with(|| entry.clone());
+        }
+        union
+    }
+
+    /// Numbers in both sets, with the spellings of both.
+    pub fn intersection(&self, other: &NumberSet) -> NumberSet {
+        let entries = self
+            .map
+            .entries
+            .iter()
+            .filter_map(|(number, entry)| {
+                let theirs = other.map.entries.get(number)?;
+                let mut entry = entry.clone();
+                entry.spellings.extend_from_slice(&theirs.spellings);
+                Some((*number, entry))
+            })
+            .collect();
+        NumberSet {
+            map: NumberMap { entries },
+        }
+    }
+
+    /// Numbers in this set and not in `other`, e.g. a campaign list minus opt-outs.
+    pub fn difference(&self, other: &NumberSet) -> NumberSet {
+        let mut difference = self.clone();
+        difference.map.suppress(other);
+        difference
+    }
+}
+
+/// Numbers added from packed form are recorded with their national spelling.
+impl FromIterator<PackedNumber> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = PackedNumber>>(numbers: I) -> Self {
+        let mut set = NumberSet::new();
+        set.extend(numbers);
+        set
+    }
+}
+
+impl Extend<PackedNumber> for NumberSet {
+    fn extend<I: IntoIterator<Item = PackedNumber>>(&mut self, numbers: I) {
+        for number in numbers {
+            let _ = self.insert(&number.to_national());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::*;
+
+    #[test]
+    fn deduplicates_spellings() {
+        let mut set = NumberSet::new();
+        assert!(set.insert("0917 123 4567").unwrap());
+        assert!(!set.insert("+639171234567").unwrap());
+        assert!(!set.insert("9171234567").unwrap());
+        assert!(set.insert("08971234567").unwrap());
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("(0917) 123-4567"));
+        assert!(!set.contains("not a number"));
+
+        let duplicates: Vec<_> = set.duplicates().collect();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].0.to_string(), "09171234567");
+        assert_eq!(
+            duplicates[0].1,
+            ["0917 123 4567", "+639171234567", "9171234567"]
+        );
+
+        assert!(set.remove("639171234567"));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn reports_rejected_numbers() {
+        let mut set = NumberSet::new();
+        let rejected = set.insert_all(["09171234567", "0917", "0281234567", "08001234567"]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(rejected.len(), 2);
+        assert_eq!(rejected[0].0, "0917");
+        assert!(matches!(rejected[0].1, MobileNetworkError::InvalidLength));
+        assert!(matches!(
+            rejected[1].1,
+            MobileNetworkError::UnrecognizedPrefix(_)
+        ));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let mut campaign = NumberSet::new();
+        campaign.insert_all(["09171234567", "09181234567", "09191234567"]);
+        let mut opted_out = NumberSet::new();
+        opted_out.insert_all(["+63 918 123 4567", "08971234567"]);
+
+        let send_to = campaign.difference(&opted_out);
+        assert_eq!(
+            send_to.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+            ["09171234567", "09191234567"]
+        );
+
+        let both = campaign.intersection(&opted_out);
+        assert_eq!(both.len(), 1);
+        assert_eq!(
+            both.spellings("09181234567").unwrap(),
+            ["09181234567", "+63 918 123 4567"]
+        );
+
+        let everyone = campaign.union(&opted_out);
+        assert_eq!(everyone.len(), 4);
+        assert_eq!(everyone.duplicates().count(), 1);
+    }
+
+    #[test]
+    fn maps_values_by_number() {
+        let mut names = NumberMap::new();
+        assert_eq!(names.insert("09171234567", "Juan").unwrap(), None);
+        assert_eq!(
+            names.insert("+639171234567", "Juan D.").unwrap(),
+            Some("Juan")
+        );
+        assert_eq!(names.get("0917 123 4567"), Some(&"Juan D."));
+        *names.get_mut("9171234567").unwrap() = "Juan Dela Cruz";
+        names.insert("09191234567", "Maria").unwrap();
+
+        let mut suppressed = NumberSet::new();
+        suppressed.insert("0919-123-4567").unwrap();
+        names.suppress(&suppressed);
+        assert_eq!(
+            names.iter().map(|(_, name)| *name).collect::<Vec<_>>(),
+            vec!["Juan Dela Cruz"]
+        );
+        assert_eq!(names.remove("09171234567"), Some("Juan Dela Cruz"));
+        assert!(names.is_empty());
+    }
+
+    #[test]
+    fn collects_packed_numbers() {
+        let packed = PackedNumber::new("09171234567").unwrap();
+        let set: NumberSet = [packed, packed].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.spellings("09171234567").unwrap().len(), 2);
+    }
+}
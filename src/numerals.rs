@@ -0,0 +1,165 @@
+use alloc::{borrow::Cow, format, string::String};
+
+use crate::errors::MobileNetworkError;
+
+/// The zero of each run of ten decimal digits (Unicode category `Nd`) that is
+/// recognised besides ASCII, e.g. full-width `０` from CJK and some mobile keyboards.
+const ZEROS: [u32; 41] = [
+    0x0660,  // Arabic-Indic
+    0x06F0,  // Extended Arabic-Indic (Persian, Urdu)
+    0x07C0,  // NKo
+    0x0966,  // Devanagari
+    0x09E6,  // Bengali
+    0x0A66,  // Gurmukhi
+    0x0AE6,  // Gujarati
+    0x0B66,  // Oriya
+    0x0BE6,  // Tamil
+    0x0C66,  // Telugu
+    0x0CE6,  // Kannada
+    0x0D66,  // Malayalam
+    0x0DE6,  // Sinhala Lith
+    0x0E50,  // Thai
+    0x0ED0,  // Lao
+    0x0F20,  // Tibetan
+    0x1040,  // Myanmar
+    0x1090,  // Myanmar Shan
+    0x17E0,  // Khmer
+    0x1810,  // Mongolian
+    0x1946,  // Limbu
+    0x19D0,  // New Tai Lue
+    0x1A80,  // Tai Tham Hora
+    0x1A90,  // Tai Tham Tham
+    0x1B50,  // Balinese
+    0x1BB0,  // Sundanese
+    0x1C40,  // Lepcha
+    0x1C50,  // Ol Chiki
+    0xA620,  // Vai
+    0xA8D0,  // Saurashtra
+    0xA900,  // Kayah Li
+    0xA9D0,  // Javanese
+    0xA9F0,  // Myanmar Tai Laing
+    0xAA50,  // Cham
+    0xABF0,  // Meetei Mayek
+    0xFF10,  // Full-width
+    0x1D7CE, // Mathematical bold
+    0x1D7D8, // Mathematical double-struck
+    0x1D7E2, // Mathematical sans-serif
+    0x1D7EC, // Mathematical sans-serif bold
+    0x1D7F6, // Mathematical monospace
+];
+
+/// The ASCII digit for a recognised non-ASCII decimal digit, e.g. `'7'` for `'٧'`.
+/// Returns `None` for ASCII digits and for anything that is not a decimal digit,
+/// including numeric characters such as `'½'` or `'Ⅻ'`.
+pub fn to_ascii_digit(c: char) -> Option<char> {
+    if c.is_ascii() {
+        return None;
+    }
+    let code = c as u32;
+    ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&code))
+        .map(|zero| char::from(b'0' + (code - zero) as u8))
+}
+
+/// Replaces every recognised non-ASCII decimal digit in `number` with its ASCII
+/// digit, leaving everything else as it is. Borrows when there is nothing to replace.
+pub fn fold(number: &str) -> Cow<'_, str> {
+    if number.chars().all(|c| to_ascii_digit(c).is_none()) {
+        return Cow::Borrowed(number);
+    }
+    Cow::Owned(
+        number
+            .chars()
+            .map(|c| to_ascii_digit(c).unwrap_or(c))
+            .collect::<String>(),
+    )
+}
+
+/// Fails with `UnicodeDigit` at the first recognised non-ASCII decimal digit in
+/// `number`, naming it and its byte offset.
+pub(crate) fn reject(number: &str) -> Result<(), MobileNetworkError> {
+    match number
+        .char_indices()
+        .find(|&(_, c)| to_ascii_digit(c).is_some())
+    {
+        Some((at, c)) => Err(MobileNetworkError::UnicodeDigit(format!(
+            "'{}' (U+{:04X}) at byte {}",
+            c, c as u32, at
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Like [`reject`] for raw bytes; bytes that are not UTF-8 contain no digits to
+/// report.
+pub(crate) fn reject_bytes(number: &[u8]) -> Result<(), MobileNetworkError> {
+    core::str::from_utf8(number).map_or(Ok(()), reject)
+}
+
+/// What to do with decimal digits from other scripts, such as full-width `０９１７`
+/// or Arabic-Indic `٠٩١٧`.
+///
+/// Lookups and validation only accept ASCII digits and fail with `UnicodeDigit` on
+/// any other decimal digit. `Fold` opts in to converting them to ASCII first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DigitPolicy {
+    /// Fail with `UnicodeDigit`.
+    #[default]
+    Reject,
+    /// Convert to ASCII digits.
+    Fold,
+}
+
+impl DigitPolicy {
+    /// Applies the policy to `number`, returning it with ASCII digits only.
+    pub fn apply(self, number: &str) -> Result<Cow<'_, str>, MobileNetworkError> {
+        match self {
+            DigitPolicy::Reject => reject(number).map(|_| Cow::Borrowed(number)),
+            DigitPolicy::Fold => Ok(fold(number)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn maps_digits_of_other_scripts() {
+        assert_eq!(to_ascii_digit('０'), Some('0'));
+        assert_eq!(to_ascii_digit('９'), Some('9'));
+        assert_eq!(to_ascii_digit('٧'), Some('7'));
+        assert_eq!(to_ascii_digit('۴'), Some('4'));
+        assert_eq!(to_ascii_digit('९'), Some('9'));
+        assert_eq!(to_ascii_digit('𝟗'), Some('9'));
+        assert_eq!(to_ascii_digit('7'), None);
+        assert_eq!(to_ascii_digit('½'), None);
+        assert_eq!(to_ascii_digit('Ⅻ'), None);
+        assert_eq!(to_ascii_digit('a'), None);
+    }
+
+    #[test]
+    fn folds_only_digits() {
+        assert_eq!(fold("０９１７-１２３-４５６７"), "0917-123-4567");
+        assert_eq!(fold("٠٩١٧١٢٣٤٥٦٧"), "09171234567");
+        assert!(matches!(fold("09171234567"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn applies_policy() {
+        let error = DigitPolicy::Reject.apply("091７1234567").unwrap_err();
+        assert!(matches!(error, MobileNetworkError::UnicodeDigit(_)));
+        assert_eq!(
+            error.to_string(),
+            "Non-ASCII digit '７' (U+FF17) at byte 3; only 0-9 are accepted"
+        );
+        assert_eq!(
+            DigitPolicy::Fold.apply("091７1234567").unwrap(),
+            "09171234567"
+        );
+        assert_eq!(DigitPolicy::Reject.apply("0917 abc").unwrap(), "0917 abc");
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::string::ToString;
+use core::{fmt, str::FromStr};
+
+use crate::errors::MobileNetworkError;
+
+/// A mobile network operator. The discriminants are stable codes, shared with
+/// `PhOperator` in the C API, and safe to store; `0` is kept for "unknown".
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Operator {
+    Globe = 1,
+    Smart = 2,
+    Sun = 3,
+    Tnt = 4,
+    Dito = 5,
+}
+
+/// Names accepted by `FromStr`, compared ignoring case and anything but letters and
+/// digits, so "Talk 'N Text" matches `talkntext`. TM (Touch Mobile) is Globe's
+/// prepaid brand and is served on Globe prefixes.
+const ALIASES: [(&str, Operator); 14] = [
+    ("globe", Operator::Globe),
+    ("globetelecom", Operator::Globe),
+    ("tm", Operator::Globe),
+    ("touchmobile", Operator::Globe),
+    ("smart", Operator::Smart),
+    ("smartcommunications", Operator::Smart),
+    ("sun", Operator::Sun),
+    ("suncellular", Operator::Sun),
+    ("tnt", Operator::Tnt),
+    ("talkntext", Operator::Tnt),
+    ("dito", Operator::Dito),
+    ("ditotelecommunity", Operator::Dito),
+    ("ditotel", Operator::Dito),
+    ("ditotelecom", Operator::Dito),
+];
+
+impl Operator {
+    /// Every known operator, in code order.
+    pub const ALL: [Operator; 5] = [
+        Operator::Globe,
+        Operator::Smart,
+        Operator::Sun,
+        Operator::Tnt,
+        Operator::Dito,
+    ];
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Operator::Globe => "globe",
+            Operator::Smart => "smart",
+            Operator::Sun => "sun",
+            Operator::Tnt => "tnt",
+            Operator::Dito => "dito",
+        }
+    }
+
+    /// The stable numeric code of this operator.
+    pub const fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Operator::Globe),
+            2 => Some(Operator::Smart),
+            3 => Some(Operator::Sun),
+            4 => Some(Operator::Tnt),
+            5 => Some(Operator::Dito),
+            _ => None,
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = Operator> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses an operator name or alias, e.g. `"TNT"`, `"Talk N Text"` or `"tm"`.
+impl FromStr for Operator {
+    type Err = MobileNetworkError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let folded = || {
+            name.chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+        };
+        ALIASES
+            .iter()
+            .find(|(alias, _)| folded().eq(alias.chars()))
+            .map(|&(_, operator)| operator)
+            .ok_or_else(|| MobileNetworkError::UnknownOperator(name.trim().to_string()))
+    }
+}
+
+impl TryFrom<u8> for Operator {
+    type Error = MobileNetworkError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Self::from_code(code).ok_or_else(|| MobileNetworkError::UnknownOperator(code.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn parses_names() {
+        assert_eq!("globe".parse::<Operator>().unwrap(), Operator::Globe);
+        assert_eq!(" DITO ".parse::<Operator>().unwrap(), Operator::Dito);
+        assert_eq!("TM".parse::<Operator>().unwrap(), Operator::Globe);
+        assert!(matches!(
+            "touch".parse::<Operator>(),
+            Err(MobileNetworkError::UnknownOperator(name)) if name == "touch"
+        ));
+    }
+
+    #[test]
+    fn parses_aliases() {
+        for alias in ["Talk N Text", "tnt", "TNT", "Talk 'N Text", "talk-n-text"] {
+            assert_eq!(
+                alias.parse::<Operator>().unwrap(),
+                Operator::Tnt,
+                "{}",
+                alias
+            );
+        }
+        assert_eq!(
+            "Smart Communications".parse::<Operator>().unwrap(),
+            Operator::Smart
+        );
+        assert_eq!("Sun Cellular".parse::<Operator>().unwrap(), Operator::Sun);
+        assert!("".parse::<Operator>().is_err());
+        assert!("glóbe".parse::<Operator>().is_err());
+    }
+
+    #[test]
+    fn round_trips_names_and_codes() {
+        for operator in Operator::iter() {
+            assert_eq!(operator.name().parse::<Operator>().unwrap(), operator);
+            assert_eq!(Operator::from_code(operator.code()), Some(operator));
+            assert_eq!(Operator::try_from(operator.code()).unwrap(), operator);
+        }
+        assert_eq!(Operator::Globe.code(), 1);
+        assert_eq!(Operator::Dito.code(), 5);
+        assert_eq!(Operator::from_code(0), None);
+        assert!(Operator::try_from(6).is_err());
+    }
+
+    #[test]
+    fn orders_by_code() {
+        let set: BTreeSet<Operator> = [Operator::Dito, Operator::Globe, Operator::Sun].into();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [Operator::Globe, Operator::Sun, Operator::Dito]
+        );
+        assert!(Operator::Smart < Operator::Tnt);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::operator::Operator;
+
+/// Public details of an operator, for display and for integrations such as SMPP
+/// that address networks by MCC/MNC.
+///
+/// TNT is a Smart brand and shares Smart's network codes; Sun was Digitel's network
+/// and keeps its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OperatorInfo {
+    pub operator: Operator,
+    /// Name as shown to subscribers, e.g. `TNT`.
+    pub brand: &'static str,
+    /// The company holding the licence.
+    pub legal_name: &'static str,
+    pub mcc: &'static str,
+    pub mnc: &'static str,
+    /// MCC and MNC together (the PLMN id), as reported by HLR lookups, e.g. `51502`.
+    pub network_code: &'static str,
+    pub website: &'static str,
+    /// Brand colour as a `#rrggbb` hex string.
+    pub color: &'static str,
+    /// Customer support number to dial from the operator's own network.
+    pub hotline: &'static str,
+}
+
+const GLOBE: OperatorInfo = OperatorInfo {
+    operator: Operator::Globe,
+    brand: "Globe",
+    legal_name: "Globe Telecom, Inc.",
+    mcc: "515",
+    mnc: "02",
+    network_code: "51502",
+    website: "https://www.globe.com.ph",
+    color: "#0056a7",
+    hotline: "211",
+};
+
+const SMART: OperatorInfo = OperatorInfo {
+    operator: Operator::Smart,
+    brand: "Smart",
+    legal_name: "Smart Communications, Inc.",
+    mcc: "515",
+    mnc: "03",
+    network_code: "51503",
+    website: "https://smart.com.ph",
+    color: "#00a94f",
+    hotline: "*888",
+};
+
+const SUN: OperatorInfo = OperatorInfo {
+    operator: Operator::Sun,
+    brand: "Sun",
+    legal_name: "Digitel Mobile Philippines, Inc.",
+    mcc: "515",
+    mnc: "05",
+    network_code: "51505",
+    website: "https://smart.com.ph",
+    color: "#f58220",
+    hotline: "*888",
+};
+
+const TNT: OperatorInfo = OperatorInfo {
+    operator: Operator::Tnt,
+    brand: "TNT",
+    legal_name: "Smart Communications, Inc.",
+    mcc: "515",
+    mnc: "03",
+    network_code: "51503",
+    website: "https://tnt.com.ph",
+    color: "#ffc20e",
+    hotline: "*888",
+};
+
+const DITO: OperatorInfo = OperatorInfo {
+    operator: Operator::Dito,
+    brand: "DITO",
+    legal_name: "DITO Telecommunity Corporation",
+    mcc: "515",
+    mnc: "66",
+    network_code: "51566",
+    website: "https://dito.ph",
+    color: "#d6001c",
+    hotline: "185",
+};
+
+impl Operator {
+    pub const fn info(&self) -> &'static OperatorInfo {
+        match self {
+            Operator::Globe => &GLOBE,
+            Operator::Smart => &SMART,
+            Operator::Sun => &SUN,
+            Operator::Tnt => &TNT,
+            Operator::Dito => &DITO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::Validate;
+
+    #[test]
+    fn has_network_codes() {
+        assert_eq!(Operator::Globe.info().network_code, "51502");
+        assert_eq!(Operator::Smart.info().mnc, "03");
+        assert_eq!(Operator::Dito.info().mnc, "66");
+        assert_eq!(
+            Operator::Tnt.info().legal_name,
+            Operator::Smart.info().legal_name
+        );
+    }
+
+    #[test]
+    fn is_consistent_for_every_operator() {
+        for operator in Operator::iter() {
+            let info = operator.info();
+            assert_eq!(info.operator, operator);
+            assert_eq!(info.network_code.len(), 5);
+            assert!(info.network_code.starts_with(info.mcc));
+            assert!(info.network_code.ends_with(info.mnc));
+            assert_eq!(info.brand.parse::<Operator>().unwrap(), operator);
+            assert!(info.color.starts_with('#') && info.color.len() == 7);
+            assert!(info.website.starts_with("https://"));
+        }
+    }
+
+    #[test]
+    fn is_reachable_from_lookup_results() {
+        let valid = Operator::Globe.validate("09171234567").unwrap();
+        assert_eq!(valid.info().brand, "Globe");
+    }
+}
@@ -0,0 +1,162 @@
+use core::{fmt, str::FromStr};
+
+use crate::{errors::MobileNetworkError, operator::Operator};
+
+/// A set of operators, e.g. the networks a promo is open to.
+///
+/// Parses from config strings such as `"globe,tm"` and displays back as a comma
+/// separated list of canonical names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OperatorSet {
+    bits: u8,
+}
+
+impl OperatorSet {
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    pub const fn all() -> Self {
+        let mut set = Self::empty();
+        let mut i = 0;
+        while i < Operator::ALL.len() {
+            set = set.with(Operator::ALL[i]);
+            i += 1;
+        }
+        set
+    }
+
+    const fn bit(operator: Operator) -> u8 {
+        1 << operator.code()
+    }
+
+    pub const fn with(self, operator: Operator) -> Self {
+        Self {
+            bits: self.bits | Self::bit(operator),
+        }
+    }
+
+    pub const fn without(self, operator: Operator) -> Self {
+        Self {
+            bits: self.bits & !Self::bit(operator),
+        }
+    }
+
+    /// Every operator not in the set: `OperatorSet::from(Operator::Dito).complement()`
+    /// allows everyone except DITO.
+    pub const fn complement(self) -> Self {
+        Self {
+            bits: !self.bits & Self::all().bits,
+        }
+    }
+
+    pub fn insert(&mut self, operator: Operator) -> bool {
+        let added = !self.contains(operator);
+        self.bits |= Self::bit(operator);
+        added
+    }
+
+    pub fn remove(&mut self, operator: Operator) -> bool {
+        let removed = self.contains(operator);
+        self.bits &= !Self::bit(operator);
+        removed
+    }
+
+    pub const fn contains(&self, operator: Operator) -> bool {
+        self.bits & Self::bit(operator) != 0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Operator> + '_ {
+        Operator::iter().filter(move |&operator| self.contains(operator))
+    }
+}
+
+impl From<Operator> for OperatorSet {
+    fn from(operator: Operator) -> Self {
+        Self::empty().with(operator)
+    }
+}
+
+impl FromIterator<Operator> for OperatorSet {
+    fn from_iter<I: IntoIterator<Item = Operator>>(operators: I) -> Self {
+        operators.into_iter().fold(Self::empty(), Self::with)
+    }
+}
+
+impl Extend<Operator> for OperatorSet {
+    fn extend<I: IntoIterator<Item = Operator>>(&mut self, operators: I) {
+        *self = operators.into_iter().fold(*self, Self::with);
+    }
+}
+
+/// Parses a comma separated list of operator names; empty entries are ignored.
+impl FromStr for OperatorSet {
+    type Err = MobileNetworkError;
+
+    fn from_str(list: &str) -> Result<Self, Self::Err> {
+        list.split(',')
+            .filter(|name| !name.trim().is_empty())
+            .map(str::parse::<Operator>)
+            .collect()
+    }
+}
+
+impl fmt::Display for OperatorSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, operator) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(operator.name())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn parses_config_strings() {
+        let set: OperatorSet = "globe, tm,Smart,".parse().unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Operator::Globe));
+        assert!(set.contains(Operator::Smart));
+        assert!(!set.contains(Operator::Dito));
+        assert_eq!(set.to_string(), "globe,smart");
+
+        assert!("".parse::<OperatorSet>().unwrap().is_empty());
+        assert!(matches!(
+            "globe,touch".parse::<OperatorSet>(),
+            Err(MobileNetworkError::UnknownOperator(name)) if name == "touch"
+        ));
+    }
+
+    #[test]
+    fn builds_and_inverts_sets() {
+        let blocked = OperatorSet::from(Operator::Dito).complement();
+        assert_eq!(blocked.len(), 4);
+        assert!(!blocked.contains(Operator::Dito));
+        assert_eq!(blocked, OperatorSet::all().without(Operator::Dito));
+
+        let mut set: OperatorSet = [Operator::Sun, Operator::Tnt].into_iter().collect();
+        assert!(set.insert(Operator::Globe));
+        assert!(!set.insert(Operator::Globe));
+        assert!(set.remove(Operator::Sun));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Operator::Globe, Operator::Tnt]
+        );
+    }
+}
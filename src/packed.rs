@@ -0,0 +1,319 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use crate::{
+    classify::classify,
+    errors::MobileNetworkError,
+    format::normalize,
+    operator::Operator,
+    validate::{digits, ValidNumber},
+};
+
+/// Subscriber numbers are the ten digits after the leading `0`, from `8000000000` to
+/// `9999999999`.
+const MIN: u64 = 8_000_000_000;
+const MAX: u64 = 9_999_999_999;
+
+/// A validated mobile number in eight bytes instead of an 11 byte string, for storing
+/// numbers in bulk.
+///
+/// Holds the ten digits after the leading `0` as a `u64`, so ordering matches the
+/// numeric (and string) order of the numbers. Converts losslessly to and from the
+/// national form. With the `serde` feature it serializes as `"09171234567"` in human
+/// readable formats and as the `u64` otherwise, and like `from_u64` deserializing
+/// does not consult the prefix table; with the `bytemuck` feature slices can be cast
+/// to bytes and checked back without copying.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedNumber(u64);
+
+impl PackedNumber {
+    /// Packs a number in any format `normalize` accepts, if an operator has its
+    /// prefix.
+    pub fn new(number: &str) -> Result<Self, MobileNetworkError> {
+        let national = normalize(number)?;
+        classify(&national)?;
+        Ok(Self::from_national(&national))
+    }
+
+    /// `national` must be 11 ASCII digits, e.g. from `normalize`.
+    pub(crate) fn from_national(national: &str) -> Self {
+        Self(national[1..].parse().unwrap_or_default())
+    }
+
+    /// Unpacks a value from [`get`](Self::get). Checks that it is a well formed mobile
+    /// number, not that its prefix is assigned, so stored numbers survive changes to
+    /// the prefix table.
+    pub const fn from_u64(value: u64) -> Option<Self> {
+        if value >= MIN && value <= MAX {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// The ten digits after the leading `0`, e.g. `9171234567`.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Big-endian bytes, which sort in the same order as the numbers, e.g. for keys
+    /// in an ordered key-value store.
+    pub const fn to_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    pub const fn from_bytes(bytes: [u8; 8]) -> Option<Self> {
+        Self::from_u64(u64::from_be_bytes(bytes))
+    }
+
+    /// The operator currently holding the prefix.
+    pub fn operator(self) -> Result<Operator, MobileNetworkError> {
+        classify(&self.to_national())
+    }
+
+    /// The number in national form, e.g. `09171234567`.
+    pub fn to_national(self) -> String {
+        alloc::format!("0{}", self.0)
+    }
+}
+
+impl From<&ValidNumber> for PackedNumber {
+    fn from(number: &ValidNumber) -> Self {
+        Self::from_national(number.as_str())
+    }
+}
+
+impl From<ValidNumber> for PackedNumber {
+    fn from(number: ValidNumber) -> Self {
+        Self::from(&number)
+    }
+}
+
+impl From<PackedNumber> for u64 {
+    fn from(number: PackedNumber) -> Self {
+        number.0
+    }
+}
+
+impl TryFrom<u64> for PackedNumber {
+    type Error = MobileNetworkError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::from_u64(value).ok_or_else(|| {
+            if (1_000_000_000..=MAX).contains(&value) {
+                MobileNetworkError::UnrecognizedPrefix(alloc::format!("0{}", value / 10_000_000))
+            } else {
+                MobileNetworkError::InvalidLength
+            }
+        })
+    }
+}
+
+impl FromStr for PackedNumber {
+    type Err = MobileNetworkError;
+
+    fn from_str(number: &str) -> Result<Self, Self::Err> {
+        Self::new(number)
+    }
+}
+
+/// Parses 11 ASCII digits straight from bytes, without normalizing.
+impl TryFrom<&[u8]> for PackedNumber {
+    type Error = MobileNetworkError;
+
+    fn try_from(number: &[u8]) -> Result<Self, Self::Error> {
+        let national = digits(number)?;
+        classify(national)?;
+        Ok(Self::from_national(national))
+    }
+}
+
+impl fmt::Display for PackedNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use core::fmt;
+
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::PackedNumber;
+    use crate::format::normalize;
+
+    impl Serialize for PackedNumber {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.collect_str(self)
+            } else {
+                serializer.serialize_u64(self.0)
+            }
+        }
+    }
+
+    struct PackedVisitor;
+
+    impl Visitor<'_> for PackedVisitor {
+        type Value = PackedNumber;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a Philippine mobile number")
+        }
+
+        /// Checks the shape like `from_u64` but not the prefix table, so numbers
+        /// stored under a prefix that has since been withdrawn still load.
+        fn visit_str<E: de::Error>(self, number: &str) -> Result<PackedNumber, E> {
+            let national = normalize(number).map_err(E::custom)?;
+            PackedNumber::from_u64(PackedNumber::from_national(&national).get())
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(number), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<PackedNumber, E> {
+            PackedNumber::from_u64(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PackedNumber {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(PackedVisitor)
+            } else {
+                deserializer.deserialize_u64(PackedVisitor)
+            }
+        }
+    }
+}
+
+// SAFETY: `PackedNumber` is a `repr(transparent)` `u64`, so it has no padding, and
+// any `u64` within `MIN..=MAX` is a valid value.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for PackedNumber {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for PackedNumber {
+    type Bits = u64;
+
+    fn is_valid_bit_pattern(bits: &u64) -> bool {
+        (MIN..=MAX).contains(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    use super::*;
+    use crate::validate::Validate;
+
+    #[test]
+    fn round_trips_strings() {
+        let packed: PackedNumber = "+63 917 123 4567".parse().unwrap();
+        assert_eq!(packed.get(), 9_171_234_567);
+        assert_eq!(packed.to_string(), "09171234567");
+        assert_eq!(packed.to_national(), "09171234567");
+        assert_eq!(packed.operator().unwrap(), Operator::Globe);
+        assert_eq!(core::mem::size_of::<PackedNumber>(), 8);
+
+        let valid = Operator::Globe.validate("09171234567").unwrap();
+        assert_eq!(PackedNumber::from(&valid), packed);
+        assert_eq!(
+            PackedNumber::try_from(b"09171234567".as_slice()).unwrap(),
+            packed
+        );
+        assert!(PackedNumber::new("08001234567").is_err());
+        assert!(PackedNumber::new("0917").is_err());
+    }
+
+    #[test]
+    fn round_trips_integers_and_bytes() {
+        let packed = PackedNumber::new("08971234567").unwrap();
+        assert_eq!(PackedNumber::from_u64(packed.get()), Some(packed));
+        assert_eq!(PackedNumber::from_bytes(packed.to_bytes()), Some(packed));
+        assert_eq!(u64::from(packed), 8_971_234_567);
+        assert_eq!(PackedNumber::from_u64(0), None);
+        assert_eq!(PackedNumber::from_u64(7_999_999_999), None);
+        assert_eq!(PackedNumber::from_u64(10_000_000_000), None);
+        assert!(matches!(
+            PackedNumber::try_from(123u64),
+            Err(MobileNetworkError::InvalidLength)
+        ));
+        assert!(matches!(
+            PackedNumber::try_from(2_812_345_678u64),
+            Err(MobileNetworkError::UnrecognizedPrefix(prefix)) if prefix == "0281"
+        ));
+    }
+
+    #[test]
+    fn orders_numerically() {
+        let mut numbers: Vec<PackedNumber> = ["09191234567", "08971234567", "09171234567"]
+            .iter()
+            .map(|number| number.parse().unwrap())
+            .collect();
+        numbers.sort();
+        let national: Vec<String> = numbers.iter().map(ToString::to_string).collect();
+        assert_eq!(national, ["08971234567", "09171234567", "09191234567"]);
+
+        let mut bytes: Vec<[u8; 8]> = numbers.iter().rev().map(|n| n.to_bytes()).collect();
+        bytes.sort();
+        assert_eq!(
+            bytes,
+            vec![
+                numbers[0].to_bytes(),
+                numbers[1].to_bytes(),
+                numbers[2].to_bytes()
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_as_string_or_integer() {
+        let packed = PackedNumber::new("09171234567").unwrap();
+        let json = serde_json::to_string(&packed).unwrap();
+        assert_eq!(json, "\"09171234567\"");
+        assert_eq!(serde_json::from_str::<PackedNumber>(&json).unwrap(), packed);
+        assert_eq!(
+            serde_json::from_str::<PackedNumber>("9171234567").unwrap(),
+            packed
+        );
+        assert!(serde_json::from_str::<PackedNumber>("\"0917\"").is_err());
+        assert!(serde_json::from_str::<PackedNumber>("42").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_unassigned_prefixes() {
+        let packed = PackedNumber::from_u64(8_001_234_567).unwrap();
+        let json = serde_json::to_string(&packed).unwrap();
+        assert_eq!(json, "\"08001234567\"");
+        assert_eq!(serde_json::from_str::<PackedNumber>(&json).unwrap(), packed);
+        assert_eq!(
+            serde_json::from_str::<PackedNumber>("\"+63 800 123 4567\"").unwrap(),
+            packed
+        );
+        assert!(serde_json::from_str::<PackedNumber>("\"02812345678\"").is_err());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn casts_without_copying() {
+        let numbers = [
+            PackedNumber::new("09171234567").unwrap(),
+            PackedNumber::new("08971234567").unwrap(),
+        ];
+        let bytes: &[u8] = bytemuck::cast_slice(&numbers);
+        assert_eq!(bytes.len(), 16);
+        let back: &[PackedNumber] = bytemuck::checked::cast_slice(bytes);
+        assert_eq!(back, numbers);
+
+        let invalid = [0u64, 9_171_234_567];
+        assert!(bytemuck::checked::try_cast_slice::<u64, PackedNumber>(&invalid).is_err());
+    }
+}
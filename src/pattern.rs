@@ -1,7 +1,11 @@
 use crate::errors::MobileNetworkError;
 
-
+/// Matches 11 digit numbers starting with one of `prefixes`, which may be 4 or 5
+/// digits long.
 pub(crate) fn generate_pattern(prefixes: &[&str]) -> Result<String, MobileNetworkError> {
-    let pattern = format!("^({})\\d{{7}}$", prefixes.join("|"));
-    Ok(pattern)
-}
\ No newline at end of file
+    let alternatives: Vec<String> = prefixes
+        .iter()
+        .map(|prefix| format!("{}\\d{{{}}}", prefix, 11usize.saturating_sub(prefix.len())))
+        .collect();
+    Ok(format!("^(?:{})$", alternatives.join("|")))
+}
@@ -0,0 +1,189 @@
+use alloc::string::String;
+use core::fmt::{self, Write as _};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    classify::{classify, classify_prefix},
+    errors::MobileNetworkError,
+    format::normalize,
+    mask::replace_numbers,
+};
+
+/// Bytes of the HMAC kept in a token: 128 bits, written as 32 hex digits.
+const TOKEN_BYTES: usize = 16;
+
+/// What a token keeps of the number in the clear, for aggregate statistics.
+///
+/// The operator and prefix come from the bundled assignments, so when a prefix is
+/// reassigned or split into five digit blocks the tokens of its numbers change.
+/// Join across dataset versions on `Nothing` tokens, which depend only on the number
+/// and the key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Preserve {
+    /// `3f9c…`
+    #[default]
+    Nothing,
+    /// `globe:3f9c…`. Numbers under unassigned prefixes cannot be tokenized.
+    Operator,
+    /// The assigned prefix that matched, e.g. `0917:3f9c…` or `09173:3f9c…`. Numbers
+    /// under unassigned prefixes cannot be tokenized.
+    Prefix,
+}
+
+/// Maps numbers to stable tokens with HMAC-SHA256 under a secret key, so datasets
+/// can be joined on numbers without storing them.
+///
+/// The number is normalized first, so `0917 123 4567` and `+639171234567` give the
+/// same token. Tokens from different keys cannot be joined, and without the key
+/// they cannot be reversed by hashing all numbers.
+#[derive(Clone)]
+pub struct Pseudonymizer {
+    mac: Hmac<Sha256>,
+    preserve: Preserve,
+}
+
+impl Pseudonymizer {
+    /// A pseudonymizer keyed with `key`, which should be at least 32 random bytes
+    /// kept out of the warehouse.
+    pub fn new(key: &[u8]) -> Self {
+        Self {
+            mac: Hmac::new_from_slice(key).expect("HMAC takes keys of any length"),
+            preserve: Preserve::Nothing,
+        }
+    }
+
+    /// Keeps the operator or prefix in front of each token.
+    pub fn preserving(mut self, preserve: Preserve) -> Self {
+        self.preserve = preserve;
+        self
+    }
+
+    /// The token of `number`, in any format `normalize` accepts.
+    pub fn token(&self, number: &str) -> Result<String, MobileNetworkError> {
+        let national = normalize(number)?;
+
+        let mut token = String::with_capacity(TOKEN_BYTES * 2 + 6);
+        match self.preserve {
+            Preserve::Nothing => {}
+            Preserve::Operator => {
+                token.push_str(classify(&national)?.name());
+                token.push(':');
+            }
+            Preserve::Prefix => {
+                token.push_str(classify_prefix(&national)?.0);
+                token.push(':');
+            }
+        }
+
+        let mut mac = self.mac.clone();
+        mac.update(national.as_bytes());
+        for byte in &mac.finalize().into_bytes()[..TOKEN_BYTES] {
+            let _ = write!(token, "{:02x}", byte);
+        }
+        Ok(token)
+    }
+
+    /// Replaces every number in `text` with `[token]`, finding numbers as
+    /// conservatively as [`redact`](crate::mask::redact). A number whose token cannot
+    /// be made, e.g. under an unassigned prefix with `Preserve::Operator`, becomes
+    /// `[mobile]`.
+    pub fn redact(&self, text: &str) -> String {
+        replace_numbers(text, |national| match self.token(national) {
+            Ok(token) => alloc::format!("[{}]", token),
+            Err(_) => String::from("[mobile]"),
+        })
+    }
+}
+
+/// Leaves the key out.
+impl fmt::Debug for Pseudonymizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pseudonymizer")
+            .field("preserve", &self.preserve)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    const KEY: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn same_number_same_token() {
+        let pseudonymizer = Pseudonymizer::new(KEY);
+        let token = pseudonymizer.token("09171234567").unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        for spelling in ["+63 917 123 4567", "639171234567", "(0917) 123-4567"] {
+            assert_eq!(pseudonymizer.token(spelling).unwrap(), token);
+        }
+        assert_ne!(pseudonymizer.token("09171234568").unwrap(), token);
+        assert_ne!(
+            Pseudonymizer::new(b"another key")
+                .token("09171234567")
+                .unwrap(),
+            token
+        );
+    }
+
+    #[test]
+    fn is_truncated_hmac_sha256() {
+        // hmac.new(b"key", b"09171234567", hashlib.sha256).hexdigest()[:32]
+        assert_eq!(
+            Pseudonymizer::new(b"key").token("0917 123 4567").unwrap(),
+            "6c8bd8099ae2e7834081d8fa0765d048"
+        );
+    }
+
+    #[test]
+    fn preserves_operator_or_prefix() {
+        let plain = Pseudonymizer::new(KEY).token("09171234567").unwrap();
+        let by_operator = Pseudonymizer::new(KEY).preserving(Preserve::Operator);
+        assert_eq!(
+            by_operator.token("09171234567").unwrap(),
+            format!("globe:{}", plain)
+        );
+        assert!(matches!(
+            by_operator.token("08001234567"),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+
+        let by_prefix = Pseudonymizer::new(KEY).preserving(Preserve::Prefix);
+        assert_eq!(
+            by_prefix.token("+639171234567").unwrap(),
+            format!("0917:{}", plain)
+        );
+        assert!(by_prefix
+            .token("09173123456")
+            .unwrap()
+            .starts_with("09173:"));
+        assert!(by_prefix
+            .token("09253123456")
+            .unwrap()
+            .starts_with("09253:"));
+        assert!(by_prefix.token("08001234567").is_err());
+        assert!(Pseudonymizer::new(KEY).token("0917").is_err());
+    }
+
+    #[test]
+    fn redacts_text_with_tokens() {
+        let pseudonymizer = Pseudonymizer::new(KEY).preserving(Preserve::Operator);
+        let token = pseudonymizer.token("09171234567").unwrap();
+        assert_eq!(
+            pseudonymizer.redact("sms to tel+639171234567 or 0800 123 4567 failed"),
+            format!("sms to tel[{}] or [mobile] failed", token)
+        );
+    }
+
+    #[test]
+    fn debug_hides_key() {
+        let debug = format!("{:?}", Pseudonymizer::new(KEY));
+        assert!(!debug.contains("0123456789"));
+    }
+}
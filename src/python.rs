@@ -0,0 +1,141 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    classify::classify,
+    errors::MobileNetworkError,
+    format::{self, NumberFormat},
+    validate::Validate,
+};
+
+mod exceptions {
+    use pyo3::{create_exception, exceptions::PyValueError};
+
+    create_exception!(ph_mobile_network, MobileNetworkError, PyValueError);
+    create_exception!(ph_mobile_network, InvalidLength, MobileNetworkError);
+    create_exception!(ph_mobile_network, NonNumeric, MobileNetworkError);
+    create_exception!(ph_mobile_network, UnrecognizedPrefix, MobileNetworkError);
+    create_exception!(ph_mobile_network, RegexError, MobileNetworkError);
+    create_exception!(ph_mobile_network, MutexError, MobileNetworkError);
+    create_exception!(ph_mobile_network, InvalidDate, MobileNetworkError);
+    create_exception!(ph_mobile_network, InvalidDataset, MobileNetworkError);
+    create_exception!(ph_mobile_network, ResolverError, MobileNetworkError);
+    create_exception!(ph_mobile_network, OperatorMismatch, MobileNetworkError);
+    create_exception!(ph_mobile_network, UnknownOperator, MobileNetworkError);
+    create_exception!(ph_mobile_network, UnicodeDigit, MobileNetworkError);
+}
+
+impl From<MobileNetworkError> for PyErr {
+    fn from(error: MobileNetworkError) -> Self {
+        let message = error.to_string();
+        match error {
+            MobileNetworkError::InvalidLength => exceptions::InvalidLength::new_err(message),
+            MobileNetworkError::NonNumeric => exceptions::NonNumeric::new_err(message),
+            MobileNetworkError::UnrecognizedPrefix(_) => {
+                exceptions::UnrecognizedPrefix::new_err(message)
+            }
+            MobileNetworkError::RegexError(_) => exceptions::RegexError::new_err(message),
+            MobileNetworkError::MutexError(_) => exceptions::MutexError::new_err(message),
+            MobileNetworkError::InvalidDate(_) => exceptions::InvalidDate::new_err(message),
+            MobileNetworkError::InvalidDataset(_) => exceptions::InvalidDataset::new_err(message),
+            MobileNetworkError::ResolverError(_) => exceptions::ResolverError::new_err(message),
+            MobileNetworkError::OperatorMismatch(_) => {
+                exceptions::OperatorMismatch::new_err(message)
+            }
+            MobileNetworkError::UnknownOperator(_) => exceptions::UnknownOperator::new_err(message),
+            MobileNetworkError::UnicodeDigit(_) => exceptions::UnicodeDigit::new_err(message),
+        }
+    }
+}
+
+/// Uses the lock-free `classify`, so threads calling in with the GIL released never
+/// contend for the global prefix lists.
+fn operator_name(number: &str) -> Result<String, MobileNetworkError> {
+    classify(number).map(|operator| operator.name().into())
+}
+
+/// Returns the 11 digit national form of `number`.
+#[pyfunction]
+fn parse(number: &str) -> PyResult<String> {
+    Ok(format::normalize(number)?)
+}
+
+/// Returns `True` for a valid number, raising the matching error otherwise.
+#[pyfunction]
+fn validate(number: &str) -> PyResult<bool> {
+    classify(number)?.validate(number)?;
+    Ok(true)
+}
+
+/// Formats `number` as `"national"`, `"international"` or `"e164"`.
+#[pyfunction]
+#[pyo3(name = "format", signature = (number, style = "national"))]
+fn format_number(number: &str, style: &str) -> PyResult<String> {
+    let format = match style {
+        "national" => NumberFormat::National,
+        "international" => NumberFormat::International,
+        "e164" => NumberFormat::E164,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "unknown format style: {}",
+                style
+            )))
+        }
+    };
+    Ok(format::format(number, format)?)
+}
+
+/// Returns the operator name of `number`.
+#[pyfunction]
+fn operator_of(number: &str) -> PyResult<String> {
+    Ok(operator_name(number)?)
+}
+
+/// Returns the operator name of every number, or `None` where it cannot be classified.
+#[pyfunction]
+fn classify_many(py: Python<'_>, numbers: Vec<String>) -> Vec<Option<String>> {
+    py.detach(|| {
+        numbers
+            .iter()
+            .map(|number| operator_name(number).ok())
+            .collect()
+    })
+}
+
+#[pymodule]
+fn ph_mobile_network(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add(
+        "MobileNetworkError",
+        py.get_type::<exceptions::MobileNetworkError>(),
+    )?;
+    m.add("InvalidLength", py.get_type::<exceptions::InvalidLength>())?;
+    m.add("NonNumeric", py.get_type::<exceptions::NonNumeric>())?;
+    m.add(
+        "UnrecognizedPrefix",
+        py.get_type::<exceptions::UnrecognizedPrefix>(),
+    )?;
+    m.add("RegexError", py.get_type::<exceptions::RegexError>())?;
+    m.add("MutexError", py.get_type::<exceptions::MutexError>())?;
+    m.add("InvalidDate", py.get_type::<exceptions::InvalidDate>())?;
+    m.add(
+        "InvalidDataset",
+        py.get_type::<exceptions::InvalidDataset>(),
+    )?;
+    m.add("ResolverError", py.get_type::<exceptions::ResolverError>())?;
+    m.add(
+        "OperatorMismatch",
+        py.get_type::<exceptions::OperatorMismatch>(),
+    )?;
+    m.add(
+        "UnknownOperator",
+        py.get_type::<exceptions::UnknownOperator>(),
+    )?;
+    m.add("UnicodeDigit", py.get_type::<exceptions::UnicodeDigit>())?;
+
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(format_number, m)?)?;
+    m.add_function(wrap_pyfunction!(operator_of, m)?)?;
+    m.add_function(wrap_pyfunction!(classify_many, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use super::{watch::Watcher, Registry, ReloadEvent};
+use crate::{
+    dataset::Dataset,
+    errors::MobileNetworkError,
+    operator::Operator,
+    validate::{ValidNumber, Validate},
+};
+
+/// A shared, swappable [`Registry`]. Lookups work on a snapshot, so replacing the
+/// registry never shows a half updated or empty table to lookups already running.
+#[derive(Debug, Clone)]
+pub struct RegistryHandle {
+    current: Arc<RwLock<Arc<Registry>>>,
+}
+
+impl RegistryHandle {
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(registry))),
+        }
+    }
+
+    /// The registry lookups currently go to. It stays valid after a swap.
+    pub fn snapshot(&self) -> Arc<Registry> {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(&current)
+    }
+
+    /// Atomically replaces the registry, returning the previous one.
+    pub fn swap(&self, registry: Registry) -> Arc<Registry> {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        std::mem::replace(&mut *current, Arc::new(registry))
+    }
+
+    /// Loads the dataset at `path` and swaps it in. On error the current registry is kept.
+    pub fn reload(&self, path: impl AsRef<Path>) -> Result<Arc<Registry>, MobileNetworkError> {
+        let dataset = Dataset::load(path)?;
+        Ok(self.swap(Registry::new(dataset)))
+    }
+
+    pub fn classify(&self, number: &str) -> Result<Operator, MobileNetworkError> {
+        self.snapshot().classify(number)
+    }
+
+    /// Polls `path` every `interval` and reloads the registry whenever the file changes.
+    /// `on_event` is called from the watcher thread after every reload attempt.
+    /// Watching stops when the returned [`Watcher`] is dropped.
+    pub fn watch(
+        &self,
+        path: impl AsRef<Path>,
+        interval: Duration,
+        on_event: impl Fn(&ReloadEvent) + Send + 'static,
+    ) -> Watcher {
+        Watcher::spawn(
+            self.clone(),
+            path.as_ref().to_path_buf(),
+            interval,
+            on_event,
+        )
+    }
+}
+
+impl Validate for RegistryHandle {
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
+        self.snapshot().validate_bytes(number)
+    }
+}
+
+impl Default for RegistryHandle {
+    fn default() -> Self {
+        Self::new(Registry::bundled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn swap_keeps_existing_snapshots() {
+        let handle = RegistryHandle::default();
+        let before = handle.snapshot();
+
+        let empty = Dataset::parse("prefix,operator,effective_from,effective_to,source,date\n");
+        handle.swap(Registry::new(empty.unwrap()));
+
+        assert_eq!(before.classify("09171234567").unwrap(), Operator::Globe);
+        assert!(matches!(
+            handle.classify("09171234567"),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn lookups_never_see_an_empty_registry_during_swaps() {
+        let handle = RegistryHandle::default();
+        let reader = {
+            let handle = handle.clone();
+            thread::spawn(move || {
+                for _ in 0..10_000 {
+                    assert_eq!(handle.classify("09171234567").unwrap(), Operator::Globe);
+                }
+            })
+        };
+        for _ in 0..1_000 {
+            handle.swap(Registry::bundled());
+        }
+        reader.join().unwrap();
+    }
+
+    #[test]
+    fn failed_reload_keeps_current_registry() {
+        let handle = RegistryHandle::default();
+        assert!(matches!(
+            handle.reload("/nonexistent/prefixes.csv"),
+            Err(MobileNetworkError::InvalidDataset(_))
+        ));
+        assert_eq!(handle.classify("09171234567").unwrap(), Operator::Globe);
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    classify::{longest_match, longest_prefix},
+    dataset::{Assignment, Dataset},
+    date::Date,
+    errors::MobileNetworkError,
+    operator::Operator,
+    operator_set::OperatorSet,
+    validate::{digits, ValidNumber, Validate},
+};
+
+#[cfg(feature = "std")]
+mod handle;
+mod ranges;
+#[cfg(feature = "std")]
+mod watch;
+
+#[cfg(feature = "std")]
+pub use handle::RegistryHandle;
+pub use ranges::{NumberRange, RangeNumbers};
+#[cfg(feature = "std")]
+pub use watch::{ReloadEvent, Watcher};
+
+/// An immutable lookup table over the current assignments of a [`Dataset`].
+#[derive(Debug, Clone)]
+pub struct Registry {
+    dataset: Dataset,
+    prefixes: Vec<(String, Operator)>,
+}
+
+impl Registry {
+    /// A registry of the assignments effective today, like `classify`. Without the
+    /// `std` feature there is no clock, so it uses those without an `effective_to`.
+    pub fn new(dataset: Dataset) -> Self {
+        #[cfg(feature = "std")]
+        return Self::as_of(dataset, Date::today());
+        #[cfg(not(feature = "std"))]
+        return Self::from_assignments(dataset, |assignment| assignment.is_current());
+    }
+
+    /// A registry of the assignments effective on `date`.
+    pub fn as_of(dataset: Dataset, date: Date) -> Self {
+        Self::from_assignments(dataset, |assignment| assignment.is_effective_on(date))
+    }
+
+    fn from_assignments(dataset: Dataset, effective: impl Fn(&Assignment) -> bool) -> Self {
+        let prefixes = dataset
+            .assignments()
+            .iter()
+            .filter(|assignment| effective(assignment))
+            .map(|assignment| (assignment.prefix.clone(), assignment.operator))
+            .collect();
+        Self { dataset, prefixes }
+    }
+
+    pub fn bundled() -> Self {
+        Self::new(Dataset::bundled())
+    }
+
+    pub fn dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Returns the operator of `number`; the longest matching prefix wins.
+    pub fn classify(&self, number: &str) -> Result<Operator, MobileNetworkError> {
+        longest_match(
+            number,
+            self.prefixes
+                .iter()
+                .map(|(prefix, operator)| (prefix.as_str(), *operator)),
+        )
+    }
+
+    /// Like `classify`, but also returns the prefix that matched, e.g. `09253`.
+    pub fn classify_prefix(&self, number: &str) -> Result<(&str, Operator), MobileNetworkError> {
+        longest_prefix(
+            number,
+            self.prefixes
+                .iter()
+                .map(|(prefix, operator)| (prefix.as_str(), *operator)),
+        )
+    }
+
+    /// Whether `number` belongs to one of `operators`.
+    pub fn matches(
+        &self,
+        number: &str,
+        operators: &OperatorSet,
+    ) -> Result<bool, MobileNetworkError> {
+        self.classify(number)
+            .map(|operator| operators.contains(operator))
+    }
+
+    pub fn prefixes_of(&self, operator: Operator) -> impl Iterator<Item = &str> {
+        self.prefixes
+            .iter()
+            .filter(move |(_, assigned)| *assigned == operator)
+            .map(|(prefix, _)| prefix.as_str())
+    }
+
+    /// The number ranges of `operator`, sorted by prefix. Numbers under a longer
+    /// prefix assigned separately are carved out, so no number is in two ranges.
+    pub fn ranges_of(&self, operator: Operator) -> Vec<NumberRange> {
+        let mut ranges: Vec<NumberRange> = self
+            .prefixes_of(operator)
+            .map(|prefix| {
+                NumberRange::new(
+                    prefix,
+                    operator,
+                    self.prefixes.iter().map(|(other, _)| other.as_str()),
+                )
+            })
+            .collect();
+        ranges.sort_unstable_by(|a, b| a.prefix().cmp(b.prefix()));
+        ranges
+    }
+
+    /// How many numbers `operator` can assign.
+    pub fn count_of(&self, operator: Operator) -> u64 {
+        self.ranges_of(operator).iter().map(NumberRange::len).sum()
+    }
+}
+
+impl Validate for Registry {
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
+        let number = digits(number)?;
+        Ok(ValidNumber::new(number, self.classify(number)?))
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(rows: &str) -> Registry {
+        Registry::new(
+            Dataset::parse(&alloc::format!(
+                "prefix,operator,effective_from,effective_to,source,date\n{}",
+                rows
+            ))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn bundled_classifies_like_classify() {
+        let registry = Registry::bundled();
+        for number in ["09171234567", "09253123456", "09461234567", "08971234567"] {
+            assert_eq!(
+                registry.classify(number).unwrap(),
+                crate::classify::classify(number).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn uses_current_assignments_only() {
+        let registry = registry(
+            "0946,tnt,,2020-01-01,test,2024-01-01\n0946,smart,2020-01-01,,test,2024-01-01\n",
+        );
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.classify("09461234567").unwrap(), Operator::Smart);
+        assert_eq!(registry.prefixes_of(Operator::Tnt).count(), 0);
+        assert!(registry
+            .matches("09461234567", &"smart,sun".parse().unwrap())
+            .unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ignores_assignments_that_start_later() {
+        let rows =
+            "0917,globe,,2030-01-01,test,2024-01-01\n0917,smart,2030-01-01,,test,2024-01-01\n";
+        let today = registry(rows);
+        assert_eq!(today.classify("09171234567").unwrap(), Operator::Globe);
+        assert_eq!(
+            today.classify("09171234567").unwrap(),
+            crate::classify::classify("09171234567").unwrap()
+        );
+
+        let later = Registry::as_of(today.dataset().clone(), Date::new(2030, 1, 1).unwrap());
+        assert_eq!(later.classify("09171234567").unwrap(), Operator::Smart);
+    }
+
+    #[test]
+    fn validates_against_own_prefixes() {
+        let registry = registry("0817,globe,,,test,2024-01-01\n");
+        let valid = registry.validate_bytes(b"08171234567").unwrap();
+        assert_eq!(valid.operator(), Operator::Globe);
+        assert!(matches!(
+            registry.validate_for(Operator::Smart, "08171234567"),
+            Err(MobileNetworkError::OperatorMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn counts_numbers_per_operator() {
+        let registry = registry(
+            "0925,sun,,,test,2024-01-01\n09253,globe,,,test,2024-01-01\n0917,globe,,,test,2024-01-01\n09173,globe,,,test,2024-01-01\n",
+        );
+        assert_eq!(registry.count_of(Operator::Sun), 9_000_000);
+        assert_eq!(registry.count_of(Operator::Globe), 11_000_000);
+        assert_eq!(registry.count_of(Operator::Dito), 0);
+
+        let globe: Vec<String> = registry
+            .ranges_of(Operator::Globe)
+            .iter()
+            .map(|range| String::from(range.prefix()))
+            .collect();
+        assert_eq!(globe, ["0917", "09173", "09253"]);
+
+        let bundled = Registry::bundled();
+        let total: u64 = Operator::iter().map(|o| bundled.count_of(o)).sum();
+        let blocks = bundled
+            .prefixes
+            .iter()
+            .filter(|(p, _)| p.len() == 4)
+            .count() as u64;
+        assert_eq!(total, blocks * 10_000_000);
+    }
+
+    #[test]
+    fn is_err_on_unrecognized_prefix() {
+        let registry = registry("0817,globe,,,test,2024-01-01\n");
+        assert!(matches!(
+            registry.classify("09171234567"),
+            Err(MobileNetworkError::UnrecognizedPrefix(_))
+        ));
+    }
+}
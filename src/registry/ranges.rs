@@ -0,0 +1,217 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write as _;
+
+use crate::operator::Operator;
+
+const NUMBER_LEN: usize = 11;
+
+/// How many numbers start with a prefix of `len` digits.
+fn block_size(len: usize) -> u64 {
+    10u64.pow((NUMBER_LEN - len) as u32)
+}
+
+/// The numbers assigned to an operator through one prefix: every number starting
+/// with `prefix`, except those under a longer prefix with its own assignment (such
+/// as Globe's `09253` inside Sun's `0925`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberRange {
+    prefix: String,
+    operator: Operator,
+    excluded: Vec<String>,
+}
+
+impl NumberRange {
+    /// `assigned` are all prefixes with an assignment; the longer ones under `prefix`
+    /// are carved out of the range.
+    pub(crate) fn new<'a>(
+        prefix: &str,
+        operator: Operator,
+        assigned: impl Iterator<Item = &'a str>,
+    ) -> Self {
+        let mut longer: Vec<&str> = assigned
+            .filter(|other| other.len() > prefix.len() && other.starts_with(prefix))
+            .collect();
+        longer.sort_unstable_by_key(|other| (other.len(), *other));
+
+        // Keep only the outermost carve-outs, which already cover any nested in them.
+        let mut excluded: Vec<String> = Vec::new();
+        for other in longer {
+            if !excluded
+                .iter()
+                .any(|outer| other.starts_with(outer.as_str()))
+            {
+                excluded.push(other.into());
+            }
+        }
+        excluded.sort_unstable();
+
+        Self {
+            prefix: prefix.into(),
+            operator,
+            excluded,
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn operator(&self) -> Operator {
+        self.operator
+    }
+
+    /// Longer prefixes carved out of this range, sorted.
+    pub fn excluded(&self) -> &[String] {
+        &self.excluded
+    }
+
+    /// Number of numbers in the range.
+    pub fn len(&self) -> u64 {
+        block_size(self.prefix.len())
+            - self
+                .excluded
+                .iter()
+                .map(|prefix| block_size(prefix.len()))
+                .sum::<u64>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, number: &str) -> bool {
+        number.len() == NUMBER_LEN
+            && number.bytes().all(|b| b.is_ascii_digit())
+            && number.starts_with(self.prefix.as_str())
+            && !self
+                .excluded
+                .iter()
+                .any(|prefix| number.starts_with(prefix.as_str()))
+    }
+
+    /// The `index`th number of the range in ascending order, without iterating.
+    pub fn get(&self, index: u64) -> Option<String> {
+        if index >= self.len() {
+            return None;
+        }
+        // Carve-outs are sorted and disjoint, so skipping each one that starts at or
+        // before the offset found so far lands on the `index`th remaining number.
+        let mut offset = index;
+        for prefix in &self.excluded {
+            let start =
+                prefix[self.prefix.len()..].parse::<u64>().unwrap_or(0) * block_size(prefix.len());
+            if start > offset {
+                break;
+            }
+            offset += block_size(prefix.len());
+        }
+        Some(self.iter().number(offset))
+    }
+
+    /// The numbers of the range in ascending order, generated on demand.
+    pub fn iter(&self) -> RangeNumbers<'_> {
+        RangeNumbers {
+            range: self,
+            next: 0,
+            end: block_size(self.prefix.len()),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a NumberRange {
+    type Item = String;
+    type IntoIter = RangeNumbers<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the numbers of a [`NumberRange`].
+#[derive(Debug, Clone)]
+pub struct RangeNumbers<'a> {
+    range: &'a NumberRange,
+    /// Offset of the next number after the prefix, i.e. its remaining digits.
+    next: u64,
+    end: u64,
+}
+
+impl RangeNumbers<'_> {
+    fn number(&self, offset: u64) -> String {
+        let width = NUMBER_LEN - self.range.prefix.len();
+        let mut number = String::with_capacity(NUMBER_LEN);
+        number.push_str(&self.range.prefix);
+        let _ = write!(number, "{:0width$}", offset, width = width);
+        number
+    }
+}
+
+impl Iterator for RangeNumbers<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while self.next < self.end {
+            let number = self.number(self.next);
+            match self
+                .range
+                .excluded
+                .iter()
+                .find(|prefix| number.starts_with(prefix.as_str()))
+            {
+                // Jump to the first number after the carved out block.
+                Some(prefix) => {
+                    let block = block_size(prefix.len());
+                    self.next = (self.next / block + 1) * block;
+                }
+                None => {
+                    self.next += 1;
+                    return Some(number);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(prefix: &str, assigned: &[&str]) -> NumberRange {
+        NumberRange::new(prefix, Operator::Sun, assigned.iter().copied())
+    }
+
+    #[test]
+    fn sizes_account_for_carve_outs() {
+        let whole = range("0925", &["0925", "0926"]);
+        assert_eq!(whole.len(), 10_000_000);
+
+        let carved = range("0925", &["0925", "09253", "09255", "092531"]);
+        assert_eq!(carved.excluded(), ["09253", "09255"]);
+        assert_eq!(carved.len(), 8_000_000);
+        assert!(carved.contains("09251234567"));
+        assert!(!carved.contains("09253123456"));
+        assert!(!carved.contains("0925123456"));
+    }
+
+    #[test]
+    fn iterates_lazily_skipping_carve_outs() {
+        let carved = range("0925", &["0925", "09250"]);
+        let mut numbers = carved.iter();
+        assert_eq!(numbers.next().unwrap(), "09251000000");
+        assert_eq!(numbers.next().unwrap(), "09251000001");
+
+        let small = range("092512345", &["092512345", "0925123456"]);
+        let all: Vec<String> = small.iter().collect();
+        assert_eq!(all.len() as u64, small.len());
+        assert_eq!(all.first().unwrap(), "09251234500");
+        assert_eq!(all.last().unwrap(), "09251234599");
+        assert!(!all.iter().any(|n| n.starts_with("0925123456")));
+
+        for (index, number) in all.iter().enumerate() {
+            assert_eq!(small.get(index as u64).as_ref(), Some(number));
+        }
+        assert_eq!(small.get(small.len()), None);
+        assert_eq!(carved.get(0).unwrap(), "09251000000");
+    }
+}
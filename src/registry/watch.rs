@@ -0,0 +1,187 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::RegistryHandle;
+use crate::errors::MobileNetworkError;
+
+/// Outcome of a reload triggered by a [`Watcher`].
+#[derive(Debug)]
+pub enum ReloadEvent {
+    /// The file was loaded and swapped in; `prefixes` is the size of the new registry.
+    Reloaded { path: PathBuf, prefixes: usize },
+    /// The file could not be loaded; the previous registry is still in use.
+    Failed {
+        path: PathBuf,
+        error: MobileNetworkError,
+    },
+}
+
+/// A hash of the file's contents, `None` while it cannot be read. Datasets are
+/// small, and unlike the modification time and size this also catches a rewrite of
+/// the same length within one timestamp tick.
+fn fingerprint(path: &Path) -> Option<u64> {
+    let contents = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Background thread started by [`RegistryHandle::watch`]. Dropping it stops the thread.
+#[derive(Debug)]
+pub struct Watcher {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Watcher {
+    pub(crate) fn spawn(
+        handle: RegistryHandle,
+        path: PathBuf,
+        interval: Duration,
+        on_event: impl Fn(&ReloadEvent) + Send + 'static,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let mut last = fingerprint(&path);
+
+        let thread = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                while !stop.load(Ordering::Acquire) {
+                    thread::park_timeout(interval);
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+
+                    let current = fingerprint(&path);
+                    if current == last {
+                        continue;
+                    }
+                    last = current;
+
+                    let event = match handle.reload(&path) {
+                        Ok(_) => ReloadEvent::Reloaded {
+                            path: path.clone(),
+                            prefixes: handle.snapshot().len(),
+                        },
+                        Err(error) => ReloadEvent::Failed {
+                            path: path.clone(),
+                            error,
+                        },
+                    };
+                    on_event(&event);
+                }
+            })
+        };
+
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Stops watching and waits for the thread to finish.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::{dataset::Dataset, operator::Operator, registry::Registry};
+
+    const HEADER: &str = "prefix,operator,effective_from,effective_to,source,date\n";
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "ph-mobile-network-{}-{}.csv",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// Replaces the file in one step, so the watcher never sees it half written.
+    fn replace(path: &Path, contents: &str) {
+        let staged = path.with_extension("tmp");
+        fs::write(&staged, contents).unwrap();
+        fs::rename(&staged, path).unwrap();
+    }
+
+    #[test]
+    fn reloads_on_change_and_reports_failures() {
+        let path = temp_path("watch");
+        replace(&path, &format!("{}0817,globe,,,test,2024-01-01\n", HEADER));
+
+        let handle = RegistryHandle::new(Registry::new(Dataset::load(&path).unwrap()));
+        let (sender, events) = mpsc::channel();
+        let watcher = handle.watch(&path, Duration::from_millis(5), move |event| {
+            let _ = sender.send(match event {
+                ReloadEvent::Reloaded { prefixes, .. } => Ok(*prefixes),
+                ReloadEvent::Failed { error, .. } => Err(error.to_string()),
+            });
+        });
+        let timeout = Duration::from_secs(5);
+
+        replace(
+            &path,
+            &format!(
+                "{}0817,globe,,,test,2024-01-01\n0917,smart,,,test,2024-01-01\n",
+                HEADER
+            ),
+        );
+        assert_eq!(events.recv_timeout(timeout).unwrap(), Ok(2));
+        assert_eq!(handle.classify("09171234567").unwrap(), Operator::Smart);
+
+        replace(&path, &format!("{}0917,smart,,,test\n", HEADER));
+        let failure = events.recv_timeout(timeout).unwrap().unwrap_err();
+        assert!(failure.contains("line 2"), "{}", failure);
+        assert_eq!(handle.classify("09171234567").unwrap(), Operator::Smart);
+
+        watcher.stop();
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reloads_same_size_rewrite_with_same_mtime() {
+        let path = temp_path("watch-same-size");
+        fs::write(&path, format!("{}0917,globe,,,test,2024-01-01\n", HEADER)).unwrap();
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+
+        let handle = RegistryHandle::new(Registry::new(Dataset::load(&path).unwrap()));
+        let (sender, events) = mpsc::channel();
+        let watcher = handle.watch(&path, Duration::from_millis(5), move |event| {
+            let _ = sender.send(matches!(event, ReloadEvent::Reloaded { .. }));
+        });
+
+        fs::write(&path, format!("{}0917,smart,,,test,2024-01-01\n", HEADER)).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert!(events.recv_timeout(Duration::from_secs(5)).unwrap());
+        assert_eq!(handle.classify("09171234567").unwrap(), Operator::Smart);
+
+        watcher.stop();
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use super::{unanswered, Resolution, Resolver};
+use crate::{errors::MobileNetworkError, operator::Operator};
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A lookup source that may need I/O, such as a portability service.
+///
+/// `Ok(None)` means the source does not know the number and the next resolver in
+/// the chain should be asked.
+pub trait AsyncResolver: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn resolve<'a>(
+        &'a self,
+        number: &'a str,
+    ) -> BoxFuture<'a, Result<Option<Operator>, MobileNetworkError>>;
+}
+
+/// Every synchronous [`Resolver`] can take part in an async chain.
+impl<R: Resolver> AsyncResolver for R {
+    fn name(&self) -> &str {
+        Resolver::name(self)
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        number: &'a str,
+    ) -> BoxFuture<'a, Result<Option<Operator>, MobileNetworkError>> {
+        Box::pin(async move { Resolver::resolve(self, number) })
+    }
+}
+
+struct Stage {
+    resolver: Box<dyn AsyncResolver>,
+    timeout: Option<Duration>,
+}
+
+/// Asks each resolver in turn until one knows the number.
+///
+/// A resolver that fails or runs past its timeout is skipped. If no resolver
+/// answers, the first failure is returned, or `UnrecognizedPrefix` when every
+/// resolver simply did not know the number; chains built with
+/// [`or_unknown`](Self::or_unknown) answer "unknown" instead.
+#[derive(Default)]
+pub struct AsyncResolverChain {
+    stages: Vec<Stage>,
+    or_unknown: bool,
+}
+
+impl AsyncResolverChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, resolver: impl AsyncResolver + 'static) -> Self {
+        self.stages.push(Stage {
+            resolver: Box::new(resolver),
+            timeout: None,
+        });
+        self
+    }
+
+    /// Adds `resolver`, giving up on it after `timeout`. Needs a tokio runtime with
+    /// the time driver enabled.
+    pub fn with_timeout(
+        mut self,
+        resolver: impl AsyncResolver + 'static,
+        timeout: Duration,
+    ) -> Self {
+        self.stages.push(Stage {
+            resolver: Box::new(resolver),
+            timeout: Some(timeout),
+        });
+        self
+    }
+
+    /// Ends the chain with a fallback that answers every number no resolver knew.
+    pub fn or_unknown(mut self) -> Self {
+        self.or_unknown = true;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    pub async fn resolve(&self, number: &str) -> Result<Resolution<'_>, MobileNetworkError> {
+        let mut first_error = None;
+
+        for stage in &self.stages {
+            let lookup = stage.resolver.resolve(number);
+            let result = match stage.timeout {
+                Some(timeout) => tokio::time::timeout(timeout, lookup)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(MobileNetworkError::ResolverError(format!(
+                            "{} timed out after {:?}",
+                            stage.resolver.name(),
+                            timeout
+                        )))
+                    }),
+                None => lookup.await,
+            };
+
+            match result {
+                Ok(Some(operator)) => {
+                    return Ok(Resolution {
+                        operator: Some(operator),
+                        resolver: stage.resolver.name(),
+                    })
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        unanswered(number, first_error, self.or_unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        registry::RegistryHandle,
+        resolver::{mock::MockResolver, PortabilityCache},
+    };
+
+    async fn operator(chain: &AsyncResolverChain, number: &str) -> Operator {
+        chain.resolve(number).await.unwrap().operator.unwrap()
+    }
+
+    #[tokio::test]
+    async fn answers_from_first_resolver_that_knows() {
+        let cache = PortabilityCache::new();
+        cache.insert("09171234567", Operator::Smart).unwrap();
+        let chain = AsyncResolverChain::new()
+            .with(cache)
+            .with(RegistryHandle::default());
+
+        let resolution = chain.resolve("09171234567").await.unwrap();
+        assert_eq!(resolution.operator, Some(Operator::Smart));
+        assert_eq!(resolution.resolver, "portability");
+        assert_eq!(operator(&chain, "09171234568").await, Operator::Globe);
+    }
+
+    #[tokio::test]
+    async fn falls_back_past_failures() {
+        let failing = MockResolver::new("crm").failing("connection refused");
+        let remote = MockResolver::new("remote").answer("08001234567", Operator::Dito);
+        let chain = AsyncResolverChain::new()
+            .with(RegistryHandle::default())
+            .with(failing.clone())
+            .with(remote.clone());
+
+        assert_eq!(
+            chain.resolve("08001234567").await.unwrap().resolver,
+            "remote"
+        );
+        assert_eq!(operator(&chain, "09171234567").await, Operator::Globe);
+        assert_eq!(failing.calls(), 1);
+        assert_eq!(remote.calls(), 1);
+
+        assert!(matches!(
+            chain.resolve("08001234568").await,
+            Err(MobileNetworkError::ResolverError(reason)) if reason.contains("connection refused")
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn skips_resolvers_that_time_out() {
+        let slow = MockResolver::new("slow")
+            .answer("08001234567", Operator::Sun)
+            .with_delay(Duration::from_secs(5));
+        let fallback = MockResolver::new("fallback").answer("08001234567", Operator::Dito);
+        let chain = AsyncResolverChain::new()
+            .with_timeout(slow, Duration::from_millis(100))
+            .with(fallback);
+        assert_eq!(operator(&chain, "08001234567").await, Operator::Dito);
+
+        let chain = AsyncResolverChain::new().with_timeout(
+            MockResolver::new("slow").with_delay(Duration::from_secs(5)),
+            Duration::from_millis(100),
+        );
+        assert!(matches!(
+            chain.resolve("08001234567").await,
+            Err(MobileNetworkError::ResolverError(reason)) if reason.starts_with("slow timed out")
+        ));
+    }
+
+    #[tokio::test]
+    async fn is_err_when_nobody_knows() {
+        let chain = AsyncResolverChain::new().with(MockResolver::new("empty"));
+        assert!(matches!(
+            chain.resolve("08001234567").await,
+            Err(MobileNetworkError::UnrecognizedPrefix(prefix)) if prefix == "0800"
+        ));
+        assert!(AsyncResolverChain::new()
+            .resolve("09171234567")
+            .await
+            .is_err());
+
+        let chain = chain.or_unknown();
+        assert!(chain.resolve("08001234567").await.unwrap().is_unknown());
+    }
+}
@@ -0,0 +1,147 @@
+use super::{unanswered, Resolution, Resolver};
+use crate::errors::MobileNetworkError;
+
+/// Asks each resolver in turn until one knows the number.
+///
+/// A resolver that fails is skipped. If no resolver answers, the first failure is
+/// returned, or `UnrecognizedPrefix` when every resolver simply did not know the
+/// number; chains built with [`or_unknown`](Self::or_unknown) answer "unknown" instead.
+#[derive(Default)]
+pub struct ResolverChain {
+    resolvers: Vec<Box<dyn Resolver>>,
+    or_unknown: bool,
+}
+
+impl ResolverChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, resolver: impl Resolver + 'static) -> Self {
+        self.resolvers.push(Box::new(resolver));
+        self
+    }
+
+    /// Ends the chain with a fallback that answers every number no resolver knew.
+    pub fn or_unknown(mut self) -> Self {
+        self.or_unknown = true;
+        self
+    }
+
+    /// Resolver names in the order they are asked.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.resolvers.iter().map(|resolver| resolver.name())
+    }
+
+    pub fn len(&self) -> usize {
+        self.resolvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resolvers.is_empty()
+    }
+
+    pub fn resolve(&self, number: &str) -> Result<Resolution<'_>, MobileNetworkError> {
+        let mut first_error = None;
+
+        for resolver in &self.resolvers {
+            match resolver.resolve(number) {
+                Ok(Some(operator)) => {
+                    return Ok(Resolution {
+                        operator: Some(operator),
+                        resolver: resolver.name(),
+                    })
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        unanswered(number, first_error, self.or_unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operator::Operator,
+        registry::RegistryHandle,
+        resolver::{Overrides, PortabilityCache, UNKNOWN},
+    };
+
+    struct Crm;
+
+    impl Resolver for Crm {
+        fn name(&self) -> &str {
+            "crm"
+        }
+
+        fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError> {
+            match number {
+                "08001234567" => Ok(Some(Operator::Dito)),
+                "08009999999" => Err(MobileNetworkError::ResolverError("crm: offline".into())),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    fn chain() -> ResolverChain {
+        let ported = PortabilityCache::new();
+        ported.insert("09171234567", Operator::Smart).unwrap();
+        ResolverChain::new()
+            .with(Overrides::new().with("0917000", Operator::Sun).unwrap())
+            .with(ported)
+            .with(Crm)
+            .with(RegistryHandle::default())
+    }
+
+    #[test]
+    fn annotates_which_resolver_answered() {
+        let chain = chain();
+        let resolved = |number| {
+            let resolution = chain.resolve(number).unwrap();
+            (resolution.operator.unwrap(), resolution.resolver)
+        };
+
+        assert_eq!(resolved("09170001234"), (Operator::Sun, "overrides"));
+        assert_eq!(resolved("09171234567"), (Operator::Smart, "portability"));
+        assert_eq!(resolved("08001234567"), (Operator::Dito, "crm"));
+        assert_eq!(resolved("09051234567"), (Operator::Globe, "prefixes"));
+        assert_eq!(
+            chain.resolve("08001234567").unwrap().info().unwrap().mnc,
+            "66"
+        );
+        assert_eq!(
+            chain.names().collect::<Vec<_>>(),
+            ["overrides", "portability", "crm", "prefixes"]
+        );
+    }
+
+    #[test]
+    fn reports_first_failure_when_nobody_answers() {
+        let chain = chain();
+        assert!(matches!(
+            chain.resolve("08009999999"),
+            Err(MobileNetworkError::ResolverError(_))
+        ));
+        assert!(matches!(
+            chain.resolve("08001111111"),
+            Err(MobileNetworkError::UnrecognizedPrefix(prefix)) if prefix == "0800"
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_unknown() {
+        let chain = chain().or_unknown();
+        let resolution = chain.resolve("08001111111").unwrap();
+        assert!(resolution.is_unknown());
+        assert_eq!(resolution.resolver, UNKNOWN);
+        assert_eq!(
+            chain.resolve("09051234567").unwrap().operator,
+            Some(Operator::Globe)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{AsyncResolver, BoxFuture};
+use crate::{errors::MobileNetworkError, operator::Operator};
+
+/// An in-process [`AsyncResolver`] for tests: answers from a fixed table, optionally
+/// after a delay or with a failure. Clones share the call counter.
+#[derive(Debug, Clone)]
+pub struct MockResolver {
+    name: String,
+    answers: HashMap<String, Operator>,
+    delay: Option<Duration>,
+    failure: Option<String>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl MockResolver {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            answers: HashMap::new(),
+            delay: None,
+            failure: None,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn answer(mut self, number: &str, operator: Operator) -> Self {
+        self.answers.insert(number.to_string(), operator);
+        self
+    }
+
+    /// Waits `delay` (on the tokio clock) before answering.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Fails every lookup with a `ResolverError` carrying `reason`.
+    pub fn failing(mut self, reason: &str) -> Self {
+        self.failure = Some(reason.to_string());
+        self
+    }
+
+    /// Number of lookups made so far.
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+impl AsyncResolver for MockResolver {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        number: &'a str,
+    ) -> BoxFuture<'a, Result<Option<Operator>, MobileNetworkError>> {
+        Box::pin(async move {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if let Some(delay) = self.delay {
+                tokio::time::sleep(delay).await;
+            }
+            match &self.failure {
+                Some(reason) => Err(MobileNetworkError::ResolverError(format!(
+                    "{}: {}",
+                    self.name, reason
+                ))),
+                None => Ok(self.answers.get(number).copied()),
+            }
+        })
+    }
+}
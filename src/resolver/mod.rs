@@ -0,0 +1,110 @@
+use crate::{
+    errors::MobileNetworkError, mobile_network::MobileNetwork, operator::Operator,
+    operator_info::OperatorInfo, registry::RegistryHandle, validate::Validate,
+};
+
+mod chain;
+mod overrides;
+pub mod portability;
+
+#[cfg(feature = "tokio")]
+mod asynchronous;
+#[cfg(all(feature = "tokio", any(test, feature = "test-util")))]
+pub mod mock;
+
+#[cfg(feature = "tokio")]
+pub use asynchronous::{AsyncResolver, AsyncResolverChain, BoxFuture};
+pub use chain::ResolverChain;
+pub use overrides::Overrides;
+pub use portability::PortabilityCache;
+
+/// A source that may know the operator of a number.
+///
+/// `Ok(None)` means the source does not know the number and the next resolver in
+/// the chain should be asked.
+pub trait Resolver: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError>;
+}
+
+/// The answer of a resolver chain, with the name of the resolver that gave it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolution<'a> {
+    /// `None` only when the chain ends in [`ResolverChain::or_unknown`] and no
+    /// resolver knew the number.
+    pub operator: Option<Operator>,
+    pub resolver: &'a str,
+}
+
+impl Resolution<'_> {
+    pub fn is_unknown(&self) -> bool {
+        self.operator.is_none()
+    }
+
+    pub fn info(&self) -> Option<&'static OperatorInfo> {
+        self.operator.map(|operator| operator.info())
+    }
+}
+
+/// Name reported for numbers no resolver knew, in chains built with `or_unknown`.
+pub const UNKNOWN: &str = "unknown";
+
+/// Shared ending of the sync and async chains once every resolver has been asked.
+fn unanswered(
+    number: &str,
+    first_error: Option<MobileNetworkError>,
+    or_unknown: bool,
+) -> Result<Resolution<'static>, MobileNetworkError> {
+    if or_unknown {
+        return Ok(Resolution {
+            operator: None,
+            resolver: UNKNOWN,
+        });
+    }
+    Err(first_error.unwrap_or_else(|| {
+        MobileNetworkError::UnrecognizedPrefix(number.chars().take(4).collect())
+    }))
+}
+
+/// Unrecognized prefixes are not an error for a resolver, just a number it does not know.
+fn known(
+    result: Result<Operator, MobileNetworkError>,
+) -> Result<Option<Operator>, MobileNetworkError> {
+    match result {
+        Ok(operator) => Ok(Some(operator)),
+        Err(MobileNetworkError::UnrecognizedPrefix(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Answers from the prefixes in the registry.
+impl Resolver for RegistryHandle {
+    fn name(&self) -> &str {
+        "prefixes"
+    }
+
+    fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError> {
+        known(self.classify(number))
+    }
+}
+
+/// Answers from the global prefix lists changed through `mutate`, the way
+/// [`MobileNetwork::get`] does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalPrefixes;
+
+impl Resolver for GlobalPrefixes {
+    fn name(&self) -> &str {
+        "globals"
+    }
+
+    fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError> {
+        let network = match MobileNetwork::get(number) {
+            Ok(network) => network,
+            Err(MobileNetworkError::UnrecognizedPrefix(_)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(Some(network.validate(number)?.operator()))
+    }
+}
@@ -0,0 +1,89 @@
+use super::{known, Resolver};
+use crate::{classify::longest_match, errors::MobileNetworkError, operator::Operator};
+
+/// Explicit assignments that win over every later resolver. An entry can be a whole
+/// 11 digit number or any shorter prefix; the longest matching entry applies.
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    entries: Vec<(String, Operator)>,
+}
+
+impl Overrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an override for `prefix`, replacing any previous one for the same prefix.
+    pub fn with(mut self, prefix: &str, operator: Operator) -> Result<Self, MobileNetworkError> {
+        if !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MobileNetworkError::NonNumeric);
+        }
+        if prefix.is_empty() || prefix.len() > 11 {
+            return Err(MobileNetworkError::InvalidLength);
+        }
+
+        self.entries.retain(|(existing, _)| existing != prefix);
+        self.entries.push((prefix.to_string(), operator));
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Resolver for Overrides {
+    fn name(&self) -> &str {
+        "overrides"
+    }
+
+    fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError> {
+        known(longest_match(
+            number,
+            self.entries
+                .iter()
+                .map(|(prefix, operator)| (prefix.as_str(), *operator)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_override_wins() {
+        let overrides = Overrides::new()
+            .with("0917", Operator::Smart)
+            .unwrap()
+            .with("09171234567", Operator::Dito)
+            .unwrap()
+            .with("0917", Operator::Sun)
+            .unwrap();
+
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(
+            overrides.resolve("09171234567").unwrap(),
+            Some(Operator::Dito)
+        );
+        assert_eq!(
+            overrides.resolve("09171234568").unwrap(),
+            Some(Operator::Sun)
+        );
+        assert_eq!(overrides.resolve("09181234567").unwrap(), None);
+        assert!(overrides.resolve("0917").is_err());
+    }
+
+    #[test]
+    fn is_err_on_invalid_prefix() {
+        assert!(Overrides::new().with("09a7", Operator::Globe).is_err());
+        assert!(Overrides::new().with("", Operator::Globe).is_err());
+        assert!(Overrides::new()
+            .with("091712345678", Operator::Globe)
+            .is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use super::Resolver;
+use crate::{errors::MobileNetworkError, format::normalize, operator::Operator};
+
+/// Numbers known to have been ported away from the operator their prefix belongs to.
+/// Clones share the same entries, so one cache can be filled while lookups read it.
+#[derive(Debug, Clone, Default)]
+pub struct PortabilityCache {
+    ported: Arc<RwLock<HashMap<String, Operator>>>,
+}
+
+impl PortabilityCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `number` is now served by `operator`, returning the operator
+    /// previously recorded for it.
+    pub fn insert(
+        &self,
+        number: &str,
+        operator: Operator,
+    ) -> Result<Option<Operator>, MobileNetworkError> {
+        let number = normalize(number)?;
+        let mut ported = self.ported.write().unwrap_or_else(|e| e.into_inner());
+        Ok(ported.insert(number, operator))
+    }
+
+    pub fn remove(&self, number: &str) -> Option<Operator> {
+        let number = normalize(number).ok()?;
+        let mut ported = self.ported.write().unwrap_or_else(|e| e.into_inner());
+        ported.remove(&number)
+    }
+
+    pub fn get(&self, number: &str) -> Option<Operator> {
+        let number = normalize(number).ok()?;
+        let ported = self.ported.read().unwrap_or_else(|e| e.into_inner());
+        ported.get(&number).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ported.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Resolver for PortabilityCache {
+    fn name(&self) -> &str {
+        "portability"
+    }
+
+    fn resolve(&self, number: &str) -> Result<Option<Operator>, MobileNetworkError> {
+        Ok(self.get(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_normalized_numbers() {
+        let cache = PortabilityCache::new();
+        assert_eq!(
+            cache.insert("+63 917 123 4567", Operator::Smart).unwrap(),
+            None
+        );
+        assert_eq!(cache.get("09171234567"), Some(Operator::Smart));
+        assert_eq!(cache.clone().get("9171234567"), Some(Operator::Smart));
+        assert_eq!(cache.get("09171234568"), None);
+
+        assert_eq!(cache.remove("0917-123-4567"), Some(Operator::Smart));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn is_err_on_invalid_number() {
+        let cache = PortabilityCache::new();
+        assert!(cache.insert("12345", Operator::Globe).is_err());
+        assert_eq!(cache.get("not a number"), None);
+    }
+}
@@ -1,6 +1,11 @@
 use regex::Regex;
 
-use crate::{errors::MobileNetworkError, pattern::generate_pattern, validate::Validate};
+use crate::{
+    errors::MobileNetworkError,
+    operator::Operator,
+    pattern::generate_pattern,
+    validate::{digits, ValidNumber, Validate},
+};
 
 pub struct Smart(regex::Regex);
 
@@ -14,20 +19,12 @@ impl Smart {
 }
 
 impl Validate for Smart {
-    fn validate(&self, number: &str) -> Result<bool, MobileNetworkError> {
-        if !number.chars().all(char::is_numeric) {
-            return Err(MobileNetworkError::NonNumeric);
-        }
-
-        if number.len() != 11 {
-            return Err(MobileNetworkError::InvalidLength);
-        }
-
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
+        let number = digits(number)?;
         if !self.0.is_match(number) {
             return Err(MobileNetworkError::UnrecognizedPrefix(number.to_owned()));
         }
-
-        Ok(true)
+        Ok(ValidNumber::new(number, Operator::Smart))
     }
 }
 
@@ -49,7 +46,10 @@ mod tests {
         let prefixes = ["0917", "0925"];
         let result = Smart::new(&prefixes).unwrap();
         // exact length and prefix  and is a valid number
-        assert!(result.validate("09171234567").unwrap());
+        assert_eq!(
+            result.validate("09171234567").unwrap().operator(),
+            Operator::Smart
+        );
     }
 
     #[test]
@@ -0,0 +1,353 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write as _;
+
+use crate::{
+    classify::classify_prefix, errors::MobileNetworkError, format::normalize, operator::Operator,
+    registry::Registry,
+};
+
+/// Counts for one dimension of a [`Stats`] report, with each count's share of a
+/// total.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Breakdown {
+    counts: BTreeMap<String, usize>,
+    total: usize,
+}
+
+impl Breakdown {
+    fn add(&mut self, label: &str) {
+        match self.counts.get_mut(label) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(label.into(), 1);
+            }
+        }
+    }
+
+    /// The count for `label`, 0 if it never occurred.
+    pub fn get(&self, label: &str) -> usize {
+        self.counts.get(label).copied().unwrap_or_default()
+    }
+
+    /// The share of `label` in percent, 0 if there was nothing to count.
+    pub fn percent(&self, label: &str) -> f64 {
+        percent(self.get(label), self.total)
+    }
+
+    /// The count every percentage is a share of.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Labels with their counts and percentages, most frequent first and ties in
+    /// label order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize, f64)> + '_ {
+        let mut rows: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(label, &count)| (label.as_str(), count))
+            .collect();
+        rows.sort_by_key(|&(_, count)| core::cmp::Reverse(count));
+        rows.into_iter()
+            .map(|(label, count)| (label, count, percent(count, self.total)))
+    }
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
+
+/// The distribution of a batch of numbers over operators, brands, prefixes and
+/// errors, e.g. for the share of subscribers on each network:
+///
+/// ```
+/// use ph_mobile_network::stats::Stats;
+///
+/// let stats: Stats = ["0917 123 4567", "+639181234567", "09191234567", "n/a"]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(stats.valid(), 3);
+/// assert_eq!(stats.operators().get("tnt"), 1);
+/// assert_eq!(stats.brands().percent("Globe"), 100.0 / 3.0);
+/// assert_eq!(stats.errors().get("NonNumeric"), 1);
+/// ```
+///
+/// Operators are labelled with [`Operator::name`] and brands with their display
+/// name. Prefixes are the assignments that matched, so Globe's `09253` block is
+/// counted apart from Sun's `0925`. Operator, brand and prefix shares are of the
+/// valid numbers; error shares are of every number. Percentages are not rounded
+/// until they are written out, so the rounded values of one dimension may not add
+/// up to exactly 100.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    registry: Option<Registry>,
+    operators: Breakdown,
+    brands: Breakdown,
+    prefixes: Breakdown,
+    errors: Breakdown,
+}
+
+impl Stats {
+    /// Looks numbers up with [`classify`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Looks numbers up in `registry` instead, e.g. one loaded from a newer dataset.
+    pub fn with_registry(mut self, registry: Registry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Counts a number in any format `normalize` accepts.
+    pub fn add(&mut self, number: &str) {
+        match self.lookup(number) {
+            Ok((prefix, operator)) => {
+                self.operators.add(operator.name());
+                self.brands.add(operator.info().brand);
+                self.prefixes.add(&prefix);
+                self.operators.total += 1;
+                self.brands.total += 1;
+                self.prefixes.total += 1;
+            }
+            Err(error) => self.errors.add(error.kind()),
+        }
+        self.errors.total += 1;
+    }
+
+    /// The matching prefix and its operator.
+    fn lookup(&self, number: &str) -> Result<(String, Operator), MobileNetworkError> {
+        let national = normalize(number)?;
+        let (prefix, operator) = match &self.registry {
+            Some(registry) => registry.classify_prefix(&national)?,
+            None => classify_prefix(&national)?,
+        };
+        Ok((prefix.into(), operator))
+    }
+
+    /// Every number counted.
+    pub fn total(&self) -> usize {
+        self.errors.total
+    }
+
+    /// Numbers with an assigned prefix.
+    pub fn valid(&self) -> usize {
+        self.operators.total
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.total() - self.valid()
+    }
+
+    /// Counts by operator, e.g. `globe`.
+    pub fn operators(&self) -> &Breakdown {
+        &self.operators
+    }
+
+    /// Counts by brand, e.g. `TNT`.
+    pub fn brands(&self) -> &Breakdown {
+        &self.brands
+    }
+
+    /// Counts by the assigned prefix that matched, e.g. `0917` or `09253`.
+    pub fn prefixes(&self) -> &Breakdown {
+        &self.prefixes
+    }
+
+    /// Counts by [`MobileNetworkError::kind`], e.g. `InvalidLength`.
+    pub fn errors(&self) -> &Breakdown {
+        &self.errors
+    }
+
+    /// Each dimension with its singular and plural name.
+    fn sections(&self) -> [(&'static str, &'static str, &Breakdown); 4] {
+        [
+            ("operator", "operators", &self.operators),
+            ("brand", "brands", &self.brands),
+            ("prefix", "prefixes", &self.prefixes),
+            ("error", "errors", &self.errors),
+        ]
+    }
+
+    /// `{"total":4,"valid":3,"invalid":1,"operators":[{"label":"…","count":2,"percent":66.67}],…}`
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        let _ = write!(
+            json,
+            "{{\"total\":{},\"valid\":{},\"invalid\":{}",
+            self.total(),
+            self.valid(),
+            self.invalid()
+        );
+        for (_, key, breakdown) in self.sections() {
+            let _ = write!(json, ",\"{}\":[", key);
+            for (i, (label, count, percent)) in breakdown.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                let _ = write!(
+                    json,
+                    "{{\"label\":\"{}\",\"count\":{},\"percent\":{:.2}}}",
+                    label.replace('\\', "\\\\").replace('"', "\\\""),
+                    count,
+                    percent
+                );
+            }
+            json.push(']');
+        }
+        json.push('}');
+        json
+    }
+
+    /// One `dimension,label,count,percent` row per count, after a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("dimension,label,count,percent\n");
+        for (name, _, breakdown) in self.sections() {
+            for (label, count, percent) in breakdown.iter() {
+                let label = if label.contains([',', '"']) {
+                    alloc::format!("\"{}\"", label.replace('"', "\"\""))
+                } else {
+                    label.to_string()
+                };
+                let _ = writeln!(csv, "{},{},{},{:.2}", name, label, count, percent);
+            }
+        }
+        csv
+    }
+
+    /// A summary line and one table per dimension that has counts.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let _ = writeln!(
+            markdown,
+            "{} numbers: {} valid, {} invalid",
+            self.total(),
+            self.valid(),
+            self.invalid()
+        );
+        for (name, _, breakdown) in self.sections() {
+            if breakdown.is_empty() {
+                continue;
+            }
+            let _ = writeln!(markdown, "\n| {} | count | percent |", name);
+            markdown.push_str("| --- | ---: | ---: |\n");
+            for (label, count, percent) in breakdown.iter() {
+                let _ = writeln!(markdown, "| {} | {} | {:.2}% |", label, count, percent);
+            }
+        }
+        markdown
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for Stats {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, numbers: I) {
+        for number in numbers {
+            self.add(number.as_ref());
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Stats {
+    fn from_iter<I: IntoIterator<Item = S>>(numbers: I) -> Self {
+        let mut stats = Stats::new();
+        stats.extend(numbers);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::Dataset;
+
+    const NUMBERS: [&str; 6] = [
+        "09171234567",
+        "+63 905 123 4567",
+        "0918-123-4567",
+        "09191234567",
+        "0917",
+        "08001234567",
+    ];
+
+    #[test]
+    fn counts_every_dimension() {
+        let stats: Stats = NUMBERS.into_iter().collect();
+        assert_eq!((stats.total(), stats.valid(), stats.invalid()), (6, 4, 2));
+
+        assert_eq!(stats.operators().get("globe"), 2);
+        assert_eq!(stats.operators().get("smart"), 1);
+        assert_eq!(stats.operators().get("tnt"), 1);
+        assert_eq!(stats.operators().percent("globe"), 50.0);
+        assert_eq!(stats.brands().get("TNT"), 1);
+        assert_eq!(stats.brands().percent("Smart"), 25.0);
+        assert_eq!(stats.prefixes().get("0917"), 1);
+        assert_eq!(stats.prefixes().total(), 4);
+        assert_eq!(stats.errors().get("InvalidLength"), 1);
+        assert_eq!(stats.errors().get("UnrecognizedPrefix"), 1);
+        assert_eq!(stats.errors().total(), 6);
+
+        let brands: Vec<_> = stats.brands().iter().map(|(label, ..)| label).collect();
+        assert_eq!(brands, ["Globe", "Smart", "TNT"]);
+    }
+
+    #[test]
+    fn counts_the_matching_prefix() {
+        let stats: Stats = ["09253123456", "09251234567"].into_iter().collect();
+        assert_eq!(stats.prefixes().get("09253"), 1);
+        assert_eq!(stats.prefixes().get("0925"), 1);
+        assert_eq!(stats.operators().get("globe"), 1);
+        assert_eq!(stats.operators().get("sun"), 1);
+    }
+
+    #[test]
+    fn looks_up_in_a_registry() {
+        let csv = "prefix,operator,effective_from,effective_to,source,date
+0800,dito,,,test,2024-01-01
+";
+        let dataset = Dataset::parse(csv).unwrap();
+        let mut stats = Stats::new().with_registry(Registry::new(dataset));
+        stats.extend(NUMBERS);
+        assert_eq!(stats.valid(), 1);
+        assert_eq!(stats.brands().get("DITO"), 1);
+        assert_eq!(stats.errors().get("UnrecognizedPrefix"), 4);
+    }
+
+    #[test]
+    fn writes_json_csv_and_markdown() {
+        let stats: Stats = ["09171234567", "09191234567", "09981234567", "n/a"]
+            .into_iter()
+            .collect();
+        let json = stats.to_json();
+        assert!(json.starts_with("{\"total\":4,\"valid\":3,\"invalid\":1,\"operators\":["));
+        assert!(json.contains("{\"label\":\"smart\",\"count\":2,\"percent\":66.67}"));
+        assert!(json
+            .ends_with("\"errors\":[{\"label\":\"NonNumeric\",\"count\":1,\"percent\":25.00}]}"));
+
+        let csv = stats.to_csv();
+        assert!(csv.starts_with("dimension,label,count,percent\n"));
+        assert!(csv.contains("operator,smart,2,66.67\n"));
+        assert!(csv.contains("prefix,0998,1,33.33\n"));
+        assert!(json.contains("\"prefixes\":[{\"label\":\"0917\""));
+
+        let markdown = stats.to_markdown();
+        assert!(markdown.starts_with("4 numbers: 3 valid, 1 invalid\n"));
+        assert!(markdown.contains("| brand | count | percent |"));
+        assert!(markdown.contains("| Globe | 1 | 33.33% |"));
+
+        let empty = Stats::new();
+        assert_eq!(empty.brands().percent("Globe"), 0.0);
+        assert_eq!(empty.to_markdown(), "0 numbers: 0 valid, 0 invalid\n");
+    }
+}
@@ -0,0 +1,317 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    classify::classify, errors::MobileNetworkError, format::normalize, number_set::NumberSet,
+    operator::Operator, operator_set::OperatorSet, packed::PackedNumber,
+};
+
+/// Why [`SuppressionList::check`] left a number out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// The number itself is on the list.
+    Number,
+    /// It starts with a suppressed prefix.
+    Prefix,
+    /// Its operator is suppressed.
+    Operator,
+    /// It is not a mobile number, so it cannot be checked.
+    Invalid,
+}
+
+/// A do-not-contact list: numbers in any spelling, plus optional whole prefixes and
+/// operators.
+///
+/// Lists load from text with one entry per line. Blank lines and lines starting with
+/// `#` are skipped, `prefix:0917` suppresses a prefix, `operator:dito` an operator,
+/// and anything else is a number in any format `normalize` accepts.
+#[derive(Debug, Clone, Default)]
+pub struct SuppressionList {
+    numbers: NumberSet,
+    prefixes: Vec<String>,
+    operators: OperatorSet,
+}
+
+impl SuppressionList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A list of the given numbers.
+    pub fn from_numbers<'a>(
+        numbers: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, MobileNetworkError> {
+        let mut list = Self::new();
+        for number in numbers {
+            list.insert(number)?;
+        }
+        Ok(list)
+    }
+
+    /// Parses a list, failing on the first entry that is not a number, prefix or
+    /// operator, so no opt-out is silently dropped.
+    pub fn parse(text: &str) -> Result<Self, MobileNetworkError> {
+        let mut list = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            list.add_line(line.trim()).map_err(|error| {
+                MobileNetworkError::InvalidDataset(format!("line {}: {}", index + 1, error))
+            })?;
+        }
+        Ok(list)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, MobileNetworkError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            MobileNetworkError::InvalidDataset(format!("{}: {}", path.display(), e))
+        })?;
+        Self::parse(&text).map_err(|e| match e {
+            MobileNetworkError::InvalidDataset(reason) => {
+                MobileNetworkError::InvalidDataset(format!("{}: {}", path.display(), reason))
+            }
+            other => other,
+        })
+    }
+
+    fn add_line(&mut self, line: &str) -> Result<(), MobileNetworkError> {
+        if line.is_empty() || line.starts_with('#') {
+            Ok(())
+        } else if let Some(prefix) = line.strip_prefix("prefix:") {
+            self.suppress_prefix(prefix.trim())
+        } else if let Some(operator) = line.strip_prefix("operator:") {
+            self.suppress_operator(operator.parse()?);
+            Ok(())
+        } else {
+            self.insert(line).map(|_| ())
+        }
+    }
+
+    /// Adds a number, returning `false` if it was already on the list.
+    pub fn insert(&mut self, number: &str) -> Result<bool, MobileNetworkError> {
+        self.numbers.insert(number)
+    }
+
+    /// Suppresses every number starting with `prefix`, written in national form such
+    /// as `0917` or `09173`.
+    pub fn suppress_prefix(&mut self, prefix: &str) -> Result<(), MobileNetworkError> {
+        if !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MobileNetworkError::NonNumeric);
+        }
+        if prefix.is_empty() || prefix.len() > 11 {
+            return Err(MobileNetworkError::InvalidLength);
+        }
+        if !prefix.starts_with('0') {
+            return Err(MobileNetworkError::UnrecognizedPrefix(prefix.into()));
+        }
+        if !self.prefixes.iter().any(|existing| existing == prefix) {
+            self.prefixes.push(prefix.into());
+        }
+        Ok(())
+    }
+
+    /// Suppresses every number of `operator`.
+    pub fn suppress_operator(&mut self, operator: Operator) {
+        self.operators.insert(operator);
+    }
+
+    pub fn numbers(&self) -> &NumberSet {
+        &self.numbers
+    }
+
+    pub fn prefixes(&self) -> &[String] {
+        &self.prefixes
+    }
+
+    pub fn operators(&self) -> OperatorSet {
+        self.operators
+    }
+
+    /// Why `number` must not be contacted, or `None` if it may be.
+    pub fn check(&self, number: &str) -> Option<Reason> {
+        let Ok(national) = normalize(number) else {
+            return Some(Reason::Invalid);
+        };
+        if self
+            .numbers
+            .contains_packed(PackedNumber::from_national(&national))
+        {
+            return Some(Reason::Number);
+        }
+        if self
+            .prefixes
+            .iter()
+            .any(|prefix| national.starts_with(prefix.as_str()))
+        {
+            return Some(Reason::Prefix);
+        }
+        if !self.operators.is_empty()
+            && classify(&national).is_ok_and(|operator| self.operators.contains(operator))
+        {
+            return Some(Reason::Operator);
+        }
+        None
+    }
+
+    /// Keeps the numbers of `numbers` that may be contacted, as they were given,
+    /// counting what was removed and why:
+    ///
+    /// ```
+    /// use ph_mobile_network::suppression::SuppressionList;
+    ///
+    /// let list = SuppressionList::parse("+63 917 123 4567\noperator:dito")?;
+    /// let mut filter = list.filter(["09171234567", "08971234567", "09181234567"]);
+    /// assert_eq!(filter.by_ref().collect::<Vec<_>>(), ["09181234567"]);
+    /// assert_eq!(filter.counts().removed(), 2);
+    /// # Ok::<(), ph_mobile_network::errors::MobileNetworkError>(())
+    /// ```
+    pub fn filter<I>(&self, numbers: I) -> Filter<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Filter {
+            list: self,
+            numbers: numbers.into_iter(),
+            counts: SuppressionCounts::default(),
+        }
+    }
+}
+
+/// What a [`Filter`] has let through and removed so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SuppressionCounts {
+    pub kept: usize,
+    pub numbers: usize,
+    pub prefixes: usize,
+    pub operators: usize,
+    pub invalid: usize,
+}
+
+impl SuppressionCounts {
+    pub fn removed(&self) -> usize {
+        self.numbers + self.prefixes + self.operators + self.invalid
+    }
+
+    fn count(&mut self, reason: Option<Reason>) {
+        let count = match reason {
+            None => &mut self.kept,
+            Some(Reason::Number) => &mut self.numbers,
+            Some(Reason::Prefix) => &mut self.prefixes,
+            Some(Reason::Operator) => &mut self.operators,
+            Some(Reason::Invalid) => &mut self.invalid,
+        };
+        *count += 1;
+    }
+}
+
+/// Iterator returned by [`SuppressionList::filter`].
+#[derive(Debug, Clone)]
+pub struct Filter<'a, I> {
+    list: &'a SuppressionList,
+    numbers: I,
+    counts: SuppressionCounts,
+}
+
+impl<I> Filter<'_, I> {
+    pub fn counts(&self) -> SuppressionCounts {
+        self.counts
+    }
+}
+
+impl<I> Iterator for Filter<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for number in self.numbers.by_ref() {
+            let reason = self.list.check(number.as_ref());
+            self.counts.count(reason);
+            if reason.is_none() {
+                return Some(number);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+
+    const LIST: &str = "# opt-outs
++63 917 123 4567
+0918-123-4567
+
+prefix:09253
+operator: DITO
+";
+
+    #[test]
+    fn parses_lists() {
+        let list = SuppressionList::parse(LIST).unwrap();
+        assert_eq!(list.numbers().len(), 2);
+        assert_eq!(list.prefixes(), ["09253"]);
+        assert!(list.operators().contains(Operator::Dito));
+
+        let error = SuppressionList::parse("09171234567\n0917\n").unwrap_err();
+        assert!(matches!(error, MobileNetworkError::InvalidDataset(_)));
+        assert!(error.to_string().contains("line 2"));
+        assert!(SuppressionList::parse("operator:touch").is_err());
+        assert!(SuppressionList::parse("prefix:917").is_err());
+    }
+
+    #[test]
+    fn checks_every_reason() {
+        let list = SuppressionList::parse(LIST).unwrap();
+        assert_eq!(list.check("09171234567"), Some(Reason::Number));
+        assert_eq!(list.check("639181234567"), Some(Reason::Number));
+        assert_eq!(list.check("0925 312 3456"), Some(Reason::Prefix));
+        assert_eq!(list.check("08971234567"), Some(Reason::Operator));
+        assert_eq!(list.check("hello"), Some(Reason::Invalid));
+        assert_eq!(list.check("09191234567"), None);
+        assert_eq!(list.check("09251234567"), None);
+    }
+
+    #[test]
+    fn filters_and_counts() {
+        let list = SuppressionList::parse(LIST).unwrap();
+        let numbers = vec_of(&[
+            "09171234567",
+            "09191234567",
+            "+63 925 312 3456",
+            "08971234567",
+            "0917",
+            "0919 765 4321",
+        ]);
+        let mut filter = list.filter(&numbers);
+        let kept: Vec<&String> = filter.by_ref().collect();
+        assert_eq!(kept, [&numbers[1], &numbers[5]]);
+        assert_eq!(
+            filter.counts(),
+            SuppressionCounts {
+                kept: 2,
+                numbers: 1,
+                prefixes: 1,
+                operators: 1,
+                invalid: 1,
+            }
+        );
+        assert_eq!(filter.counts().removed(), 4);
+    }
+
+    #[test]
+    fn builds_from_numbers() {
+        let list = SuppressionList::from_numbers(["09171234567", "9171234567"]).unwrap();
+        assert_eq!(list.numbers().len(), 1);
+        assert!(SuppressionList::from_numbers(["0917"]).is_err());
+    }
+
+    fn vec_of(numbers: &[&str]) -> Vec<String> {
+        numbers.iter().map(ToString::to_string).collect()
+    }
+}
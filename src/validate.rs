@@ -1,5 +1,232 @@
-use crate::errors::MobileNetworkError;
+use alloc::{format, string::String};
+use core::fmt;
 
+use crate::{
+    classify::classify,
+    errors::MobileNetworkError,
+    numerals::{self, DigitPolicy},
+    operator::Operator,
+    operator_info::OperatorInfo,
+};
+
+/// A mobile number that passed validation: 11 ASCII digits and the operator they
+/// belong to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidNumber {
+    number: String,
+    operator: Operator,
+}
+
+impl ValidNumber {
+    pub(crate) fn new(number: &str, operator: Operator) -> Self {
+        Self {
+            number: number.into(),
+            operator,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.number
+    }
+
+    pub fn operator(&self) -> Operator {
+        self.operator
+    }
+
+    pub fn info(&self) -> &'static OperatorInfo {
+        self.operator.info()
+    }
+
+    /// The first four digits, e.g. `0917`.
+    pub fn prefix(&self) -> &str {
+        &self.number[..4]
+    }
+
+    pub fn into_string(self) -> String {
+        self.number
+    }
+}
+
+impl AsRef<str> for ValidNumber {
+    fn as_ref(&self) -> &str {
+        &self.number
+    }
+}
+
+impl fmt::Display for ValidNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.number)
+    }
+}
+
+/// Checks that a number is well formed and belongs to an operator.
+///
+/// Implemented by the operator and registry types, and object safe, so validators can
+/// be stored as `Box<dyn Validate>`.
 pub trait Validate {
-     fn validate(&self, number: &str) -> Result<bool, MobileNetworkError>;
- }
\ No newline at end of file
+    /// Validates a number given as raw bytes, e.g. straight from a network buffer.
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError>;
+
+    fn validate(&self, number: &str) -> Result<ValidNumber, MobileNetworkError> {
+        self.validate_bytes(number.as_bytes())
+    }
+
+    /// Validates any string type, e.g. a `String` or `Cow<str>` taken by value. Not
+    /// available on `dyn Validate`, which keeps the trait object safe.
+    fn validate_as<S: AsRef<str>>(&self, number: S) -> Result<ValidNumber, MobileNetworkError>
+    where
+        Self: Sized,
+    {
+        self.validate(number.as_ref())
+    }
+
+    /// Validates `number` after applying `digits`, so `DigitPolicy::Fold` accepts
+    /// `０９１７１２３４５６７`.
+    fn validate_with(
+        &self,
+        digits: DigitPolicy,
+        number: &str,
+    ) -> Result<ValidNumber, MobileNetworkError> {
+        self.validate(&digits.apply(number)?)
+    }
+
+    /// Validates `number` and checks that it belongs to `operator`, answering
+    /// "is this a Globe number?".
+    fn validate_for(
+        &self,
+        operator: Operator,
+        number: &str,
+    ) -> Result<ValidNumber, MobileNetworkError> {
+        let valid = self.validate(number)?;
+        if valid.operator() != operator {
+            return Err(mismatch(valid.as_str(), valid.operator(), operator));
+        }
+        Ok(valid)
+    }
+}
+
+fn mismatch(number: &str, found: Operator, expected: Operator) -> MobileNetworkError {
+    MobileNetworkError::OperatorMismatch(format!(
+        "{} belongs to {}, not {}",
+        number, found, expected
+    ))
+}
+
+/// Checks the shape shared by every number: 11 ASCII digits.
+pub(crate) fn digits(number: &[u8]) -> Result<&str, MobileNetworkError> {
+    if !number.iter().all(u8::is_ascii_digit) {
+        numerals::reject_bytes(number)?;
+        return Err(MobileNetworkError::NonNumeric);
+    }
+    if number.len() != 11 {
+        return Err(MobileNetworkError::InvalidLength);
+    }
+    // All ASCII digits, so always valid UTF-8.
+    core::str::from_utf8(number).map_err(|_| MobileNetworkError::NonNumeric)
+}
+
+/// Validates against the bundled prefixes: `Operator::Globe.validate(number)` succeeds
+/// only for Globe numbers.
+impl Validate for Operator {
+    fn validate_bytes(&self, number: &[u8]) -> Result<ValidNumber, MobileNetworkError> {
+        let number = digits(number)?;
+        let found = classify(number)?;
+        if found != *self {
+            return Err(mismatch(number, found, *self));
+        }
+        Ok(ValidNumber::new(number, found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::Cow, boxed::Box, string::ToString, vec, vec::Vec};
+
+    use super::*;
+    use crate::registry::Registry;
+
+    #[test]
+    fn returns_typed_number() {
+        let valid = Operator::Globe.validate("09171234567").unwrap();
+        assert_eq!(valid.as_str(), "09171234567");
+        assert_eq!(valid.prefix(), "0917");
+        assert_eq!(valid.operator(), Operator::Globe);
+        assert_eq!(valid.to_string(), "09171234567");
+    }
+
+    #[test]
+    fn accepts_bytes_and_owned_strings() {
+        let owned = "09171234567".to_string();
+        assert_eq!(
+            Operator::Globe.validate(&owned).unwrap(),
+            Operator::Globe.validate_bytes(owned.as_bytes()).unwrap()
+        );
+        assert!(matches!(
+            Operator::Globe.validate_bytes(b"0917\xff234567"),
+            Err(MobileNetworkError::NonNumeric)
+        ));
+    }
+
+    #[test]
+    fn accepts_any_string_type() {
+        let owned = "09171234567".to_string();
+        let borrowed: Cow<str> = Cow::Borrowed("09171234567");
+        let cow: Cow<str> = Cow::Owned("09191234567".to_string());
+        assert_eq!(
+            Operator::Globe.validate_as(owned).unwrap().as_str(),
+            "09171234567"
+        );
+        assert!(Registry::bundled().validate_as(&borrowed).is_ok());
+        assert_eq!(
+            Registry::bundled().validate_as(cow).unwrap().operator(),
+            Operator::Smart
+        );
+        assert!(Operator::Globe.validate_as(borrowed).is_ok());
+    }
+
+    #[test]
+    fn applies_digit_policy() {
+        let number = "０９１７１２３４５６７";
+        assert!(matches!(
+            Operator::Globe.validate(number),
+            Err(MobileNetworkError::UnicodeDigit(_))
+        ));
+        assert!(matches!(
+            Registry::bundled().validate_with(DigitPolicy::Reject, number),
+            Err(MobileNetworkError::UnicodeDigit(_))
+        ));
+        let valid = Registry::bundled()
+            .validate_with(DigitPolicy::Fold, number)
+            .unwrap();
+        assert_eq!(valid.as_str(), "09171234567");
+        assert_eq!(valid.operator(), Operator::Globe);
+    }
+
+    #[test]
+    fn checks_requested_operator() {
+        assert!(Operator::Smart.validate("09191234567").is_ok());
+        assert!(matches!(
+            Operator::Globe.validate("09191234567"),
+            Err(MobileNetworkError::OperatorMismatch(reason)) if reason == "09191234567 belongs to smart, not globe"
+        ));
+        assert!(Registry::bundled()
+            .validate_for(Operator::Smart, "09191234567")
+            .is_ok());
+    }
+
+    #[test]
+    fn is_object_safe() {
+        let validators: Vec<Box<dyn Validate>> =
+            vec![Box::new(Operator::Globe), Box::new(Registry::bundled())];
+        for validator in &validators {
+            assert_eq!(
+                validator.validate("09171234567").unwrap().operator(),
+                Operator::Globe
+            );
+            assert!(matches!(
+                validator.validate("0917123456"),
+                Err(MobileNetworkError::InvalidLength)
+            ));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    classify::classify,
+    format::{self, NumberFormat},
+    validate::Validate,
+};
+
+#[wasm_bindgen(typescript_custom_section)]
+const NETWORK_TYPE: &'static str = r#"
+export type Network = "globe" | "smart" | "sun" | "tnt" | "dito";
+"#;
+
+/// Returns the network name for `number`, throwing when the prefix is unknown. Like
+/// the other bindings this uses `classify`, so answers match the Rust API.
+#[wasm_bindgen(js_name = getNetwork, unchecked_return_type = "Network")]
+pub fn get_network(number: &str) -> Result<String, JsError> {
+    Ok(classify(number)?.name().into())
+}
+
+/// Returns `true` for a valid number, throwing with the reason otherwise.
+#[wasm_bindgen]
+pub fn validate(number: &str) -> Result<bool, JsError> {
+    classify(number)?.validate(number)?;
+    Ok(true)
+}
+
+#[wasm_bindgen]
+pub fn normalize(number: &str) -> Result<String, JsError> {
+    Ok(format::normalize(number)?)
+}
+
+#[wasm_bindgen]
+pub fn format(number: &str, format: NumberFormat) -> Result<String, JsError> {
+    Ok(format::format(number, format)?)
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn get_network_returns_name() {
+        assert_eq!(get_network("09171234567").unwrap(), "globe");
+    }
+
+    #[wasm_bindgen_test]
+    fn get_network_uses_longest_prefix() {
+        assert_eq!(get_network("09253123456").unwrap(), "globe");
+        assert_eq!(get_network("09251234567").unwrap(), "sun");
+        assert!(validate("09253123456").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_is_ok() {
+        assert!(validate("09171234567").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn formats_e164() {
+        assert_eq!(
+            format("0917 123 4567", NumberFormat::E164).unwrap(),
+            "+639171234567"
+        );
+    }
+}
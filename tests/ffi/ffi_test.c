@@ -0,0 +1,56 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "ph_mobile_network.h"
+
+static void test_operator_of(void) {
+    PhOperator operator_ = PH_OPERATOR_UNKNOWN;
+    assert(ph_operator_of("09171234567", &operator_) == PH_STATUS_OK);
+    assert(operator_ == PH_OPERATOR_GLOBE);
+    assert(strcmp(ph_operator_name(operator_), "globe") == 0);
+
+    assert(ph_operator_of("08971234567", &operator_) == PH_STATUS_OK);
+    assert(operator_ == PH_OPERATOR_DITO);
+
+    assert(ph_operator_of("09253123456", &operator_) == PH_STATUS_OK);
+    assert(operator_ == PH_OPERATOR_GLOBE);
+
+    assert(ph_operator_of("09871234567", &operator_) == PH_STATUS_UNRECOGNIZED_PREFIX);
+}
+
+static void test_validate(void) {
+    assert(ph_validate("09171234567") == PH_STATUS_OK);
+    assert(ph_validate("0917123456") == PH_STATUS_INVALID_LENGTH);
+    assert(ph_validate("0917abc4567") == PH_STATUS_NON_NUMERIC);
+    assert(ph_validate(NULL) == PH_STATUS_NULL_POINTER);
+}
+
+static void test_parse_and_format(void) {
+    char buffer[PH_BUFFER_LEN];
+
+    assert(ph_parse("+63 917 123 4567", buffer, sizeof buffer) == PH_STATUS_OK);
+    assert(strcmp(buffer, "09171234567") == 0);
+
+    assert(ph_format("09171234567", PH_FORMAT_INTERNATIONAL, buffer, sizeof buffer) == PH_STATUS_OK);
+    assert(strcmp(buffer, "+63 917 123 4567") == 0);
+
+    assert(ph_format("09171234567", PH_FORMAT_E164, buffer, 4) == PH_STATUS_BUFFER_TOO_SMALL);
+    assert(ph_format("09171234567", 42, buffer, sizeof buffer) == PH_STATUS_INVALID_ARGUMENT);
+}
+
+static void test_status_message(void) {
+    assert(strcmp(ph_status_message(PH_STATUS_OK), "ok") == 0);
+    assert(strlen(ph_status_message(PH_STATUS_BUFFER_TOO_SMALL)) > 0);
+    assert(strcmp(ph_status_message(1000), "unknown") == 0);
+    assert(strcmp(ph_operator_name(99), "unknown") == 0);
+}
+
+int main(void) {
+    test_operator_of();
+    test_validate();
+    test_parse_and_format();
+    test_status_message();
+    puts("ffi: all tests passed");
+    return 0;
+}
@@ -0,0 +1,10 @@
+#!/bin/sh
+# Builds the static library and runs the C test harness against the checked in header.
+set -eu
+
+cd "$(dirname "$0")/../.."
+
+cargo rustc --lib --release --features ffi --crate-type staticlib
+cc -std=c99 -Wall -Wextra -Werror -Iinclude tests/ffi/ffi_test.c \
+    target/release/libph_mobile_network.a -lpthread -ldl -lm -o target/ffi_test
+./target/ffi_test
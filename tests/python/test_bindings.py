@@ -0,0 +1,55 @@
+import unittest
+from concurrent.futures import ThreadPoolExecutor
+
+import ph_mobile_network as ph
+
+
+class BindingsTest(unittest.TestCase):
+    def test_parse(self):
+        self.assertEqual(ph.parse("+63 917 123 4567"), "09171234567")
+
+    def test_validate(self):
+        self.assertTrue(ph.validate("09171234567"))
+        self.assertTrue(ph.validate("09253123456"))
+
+    def test_format(self):
+        self.assertEqual(ph.format("09171234567"), "09171234567")
+        self.assertEqual(ph.format("09171234567", "international"), "+63 917 123 4567")
+        self.assertEqual(ph.format("09171234567", style="e164"), "+639171234567")
+        with self.assertRaises(ValueError):
+            ph.format("09171234567", "rfc3966")
+
+    def test_operator_of(self):
+        self.assertEqual(ph.operator_of("09171234567"), "globe")
+        self.assertEqual(ph.operator_of("08971234567"), "dito")
+        self.assertEqual(ph.operator_of("09253123456"), "globe")
+
+    def test_classify_many(self):
+        self.assertEqual(
+            ph.classify_many(["09171234567", "09871234567", "08971234567"]),
+            ["globe", None, "dito"],
+        )
+
+    def test_classify_many_from_threads(self):
+        numbers = ["09171234567", "09181234567", "08971234567"] * 2000
+        expected = ["globe", "tnt", "dito"] * 2000
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            results = list(pool.map(lambda _: ph.classify_many(numbers), range(32)))
+        for result in results:
+            self.assertEqual(result, expected)
+
+    def test_exceptions_mirror_errors(self):
+        with self.assertRaises(ph.InvalidLength):
+            ph.validate("0917123456")
+        with self.assertRaises(ph.NonNumeric):
+            ph.parse("0917abc4567")
+        with self.assertRaises(ph.UnrecognizedPrefix):
+            ph.operator_of("09871234567")
+        with self.assertRaises(ph.UnicodeDigit):
+            ph.validate("０９１７１２３４５６７")
+        self.assertTrue(issubclass(ph.UnrecognizedPrefix, ph.MobileNetworkError))
+        self.assertTrue(issubclass(ph.MobileNetworkError, ValueError))
+
+
+if __name__ == "__main__":
+    unittest.main()